@@ -0,0 +1,38 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use hedera_crypto::{PrivateKey, PublicKey, Signature, VerificationPolicy};
+
+fn verify(c: &mut Criterion) {
+    let keys: Vec<_> = (0..16).map(|_| PrivateKey::generate()).collect();
+    let public_keys: Vec<_> = keys.iter().map(PrivateKey::public_key).collect();
+
+    // both sides check under ZIP-215, the only policy that batches
+    let mut group = c.benchmark_group("verify");
+
+    for &size in &[16, 256, 4096] {
+        let messages: Vec<_> = (0..size).map(|i| format!("record {}", i).into_bytes()).collect();
+        let signatures: Vec<_> =
+            messages.iter().enumerate().map(|(i, message)| keys[i % 16].sign(message)).collect();
+
+        let entries: Vec<(&[u8], &Signature, &PublicKey)> =
+            (0..size).map(|i| (&messages[i][..], &signatures[i], &public_keys[i % 16])).collect();
+
+        group.throughput(Throughput::Elements(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("one_by_one", size), &entries, |b, entries| {
+            b.iter(|| {
+                entries.iter().all(|(message, signature, key)| {
+                    key.verify_with_policy(message, signature, VerificationPolicy::Zip215).is_ok()
+                })
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("batch", size), &entries, |b, entries| {
+            b.iter(|| PublicKey::verify_batch(entries, VerificationPolicy::Zip215).is_ok())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, verify);
+criterion_main!(benches);
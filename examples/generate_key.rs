@@ -13,7 +13,7 @@ fn main() -> anyhow::Result<()> {
     // WARN: don't hand out your root key
     let key = root_key.derive(0)?;
 
-    println!("private key = {}", key);
+    println!("private key = {}", key.expose_secret());
     println!("public key = {}", key.public_key());
 
     // [...]
@@ -21,7 +21,7 @@ fn main() -> anyhow::Result<()> {
     // recover your key from the mnemonic
     // this takes space-separated or comma-separated words
 
-    let recovered_mnemonic = Mnemonic::from_str(&mnemonic.to_string())?;
+    let recovered_mnemonic = Mnemonic::from_str(&mnemonic.expose_secret().to_string())?;
     let recovered_root_key = recovered_mnemonic.to_private_key("")?;
     let recovered_key = recovered_root_key.derive(0)?;
 
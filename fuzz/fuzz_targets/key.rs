@@ -0,0 +1,21 @@
+#![no_main]
+
+use std::str;
+use std::str::FromStr;
+
+use hedera_crypto::{Key, KeyList, SignatureMap};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(key) = Key::from_protobuf_bytes(data) {
+        let _ = key.validate();
+    }
+
+    let _ = SignatureMap::from_protobuf_bytes(data);
+
+    if let Ok(text) = str::from_utf8(data) {
+        let _ = Key::from_str(text);
+        let _ = KeyList::from_str(text);
+        let _ = Key::from_mirror_node_json(text);
+    }
+});
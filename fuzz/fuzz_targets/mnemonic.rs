@@ -0,0 +1,15 @@
+#![no_main]
+
+use std::str;
+use std::str::FromStr;
+
+use hedera_crypto::Mnemonic;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(text) = str::from_utf8(data) {
+        if let Ok(mnemonic) = Mnemonic::from_str(text) {
+            let _ = mnemonic.to_legacy_private_key();
+        }
+    }
+});
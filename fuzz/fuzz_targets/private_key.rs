@@ -0,0 +1,19 @@
+#![no_main]
+
+use std::str;
+use std::str::FromStr;
+
+use hedera_crypto::PrivateKey;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = PrivateKey::from_bytes(data);
+    let _ = PrivateKey::from_bytes_ed25519(data);
+    let _ = PrivateKey::from_bytes_ecdsa(data);
+    let _ = PrivateKey::from_bytes_der(data);
+
+    if let Ok(text) = str::from_utf8(data) {
+        let _ = PrivateKey::from_str(text);
+        let _ = PrivateKey::from_pem(text, "");
+    }
+});
@@ -0,0 +1,20 @@
+#![no_main]
+
+use std::str;
+use std::str::FromStr;
+
+use hedera_crypto::{EvmAddress, PublicKey};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = PublicKey::from_bytes(data);
+    let _ = PublicKey::from_bytes_ed25519(data);
+    let _ = PublicKey::from_bytes_ecdsa(data);
+    let _ = PublicKey::from_bytes_der(data);
+    let _ = EvmAddress::from_bytes(data);
+
+    if let Ok(text) = str::from_utf8(data) {
+        let _ = PublicKey::from_str(text);
+        let _ = EvmAddress::from_str(text);
+    }
+});
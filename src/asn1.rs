@@ -0,0 +1,299 @@
+use std::mem::size_of;
+
+use crate::key_algorithm::KeyAlgorithm;
+use crate::key_error::KeyError;
+
+const INTEGER: u8 = 0x02;
+const BIT_STRING: u8 = 0x03;
+const OCTET_STRING: u8 = 0x04;
+const OBJECT_IDENTIFIER: u8 = 0x06;
+const SEQUENCE: u8 = 0x30;
+
+// `[0]` and `[1]`, as used by `OneAsymmetricKey` (implicit) and `ECPrivateKey` (explicit)
+const ATTRIBUTES: u8 = 0xa0;
+const PUBLIC_KEY: u8 = 0x81;
+const EC_PARAMETERS: u8 = 0xa0;
+const EC_PUBLIC_KEY: u8 = 0xa1;
+
+// 1.3.101.112
+const ED25519_OID: &[u8] = &[0x2b, 0x65, 0x70];
+
+// 1.2.840.10045.2.1
+const EC_PUBLIC_KEY_OID: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+
+// 1.3.132.0.10, which Hedera also uses as the algorithm itself
+const SECP256K1_OID: &[u8] = &[0x2b, 0x81, 0x04, 0x00, 0x0a];
+
+/// A `SubjectPublicKeyInfo` (RFC 5280).
+pub(crate) struct SubjectPublicKeyInfo<'a> {
+    pub(crate) algorithm: KeyAlgorithm,
+    pub(crate) public_key: &'a [u8],
+}
+
+/// A PKCS#8 `PrivateKeyInfo` or `OneAsymmetricKey` (RFC 5958).
+pub(crate) struct PrivateKeyInfo<'a> {
+    pub(crate) algorithm: KeyAlgorithm,
+    pub(crate) private_key: &'a [u8],
+
+    /// Every public key embedded alongside the private key, in the document itself
+    /// or in the `ECPrivateKey` (RFC 5915) of an ECDSA key.
+    pub(crate) public_keys: Vec<&'a [u8]>,
+}
+
+impl<'a> SubjectPublicKeyInfo<'a> {
+    pub(crate) fn from_der(data: &'a [u8]) -> Result<Self, KeyError> {
+        let mut info = DerReader::new(data).read_only(SEQUENCE)?;
+        let algorithm = info.read_algorithm()?;
+        let public_key = info.read_bit_string(BIT_STRING)?;
+        info.finish()?;
+
+        Ok(Self { algorithm, public_key })
+    }
+}
+
+impl<'a> PrivateKeyInfo<'a> {
+    pub(crate) fn from_der(data: &'a [u8]) -> Result<Self, KeyError> {
+        let mut info = DerReader::new(data).read_only(SEQUENCE)?;
+
+        let version = info.read(INTEGER)?;
+        let algorithm = info.read_algorithm()?;
+        let private_key = DerReader::new(info.read(OCTET_STRING)?);
+
+        // attributes carry nothing we use
+        info.read_optional(ATTRIBUTES)?;
+
+        let public_key = match info.peek_tag() {
+            Some(PUBLIC_KEY) => Some(info.read_bit_string(PUBLIC_KEY)?),
+            _ => None,
+        };
+
+        info.finish()?;
+
+        match (version, public_key) {
+            ([0], None) | ([1], _) => {}
+            ([0], Some(_)) => return Err(KeyError::Der("public key in a version 1 document")),
+            _ => return Err(KeyError::Der("unsupported PKCS#8 version")),
+        }
+
+        let mut public_keys: Vec<_> = public_key.into_iter().collect();
+
+        let private_key = match (algorithm, private_key.peek_tag()) {
+            (KeyAlgorithm::EcdsaSecp256k1, Some(SEQUENCE)) => {
+                let mut ec_private_key = private_key.read_only(SEQUENCE)?;
+
+                if ec_private_key.read(INTEGER)? != [1] {
+                    return Err(KeyError::Der("unsupported ECPrivateKey version"));
+                }
+
+                let key = ec_private_key.read(OCTET_STRING)?;
+
+                if let Some(parameters) = ec_private_key.read_optional(EC_PARAMETERS)? {
+                    let curve = DerReader::new(parameters).read_only(OBJECT_IDENTIFIER)?;
+                    curve.expect_secp256k1()?;
+                }
+
+                if let Some(public_key) = ec_private_key.read_optional(EC_PUBLIC_KEY)? {
+                    public_keys.push(DerReader::new(public_key).read_bit_string(BIT_STRING)?);
+                }
+
+                ec_private_key.finish()?;
+                key
+            }
+
+            // the `CurvePrivateKey` of RFC 8410, which Hedera also uses for ECDSA keys
+            _ => private_key.read_only(OCTET_STRING)?.data,
+        };
+
+        Ok(Self { algorithm, private_key, public_keys })
+    }
+}
+
+/// Reads DER-encoded values one after another.
+struct DerReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> DerReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn peek_tag(&self) -> Option<u8> {
+        self.data.first().copied()
+    }
+
+    /// Reads the next value, returning its tag and contents.
+    fn read_any(&mut self) -> Result<(u8, &'a [u8]), KeyError> {
+        let (tag, rest) = self.data.split_first().ok_or(KeyError::Der("unexpected end"))?;
+
+        if tag & 0x1f == 0x1f {
+            return Err(KeyError::Der("unsupported tag"));
+        }
+
+        let (length, rest) = rest.split_first().ok_or(KeyError::Der("unexpected end"))?;
+
+        let (length, rest) = if length & 0x80 == 0 {
+            (usize::from(*length), rest)
+        } else {
+            let count = usize::from(length & 0x7f);
+
+            if count == 0 || count > size_of::<usize>() || count > rest.len() {
+                return Err(KeyError::Der("invalid length"));
+            }
+
+            let (bytes, rest) = rest.split_at(count);
+            let length = bytes.iter().fold(0, |length, byte| length << 8 | usize::from(*byte));
+
+            if bytes[0] == 0 || length < 0x80 {
+                return Err(KeyError::Der("non-minimal length"));
+            }
+
+            (length, rest)
+        };
+
+        if length > rest.len() {
+            return Err(KeyError::Der("unexpected end"));
+        }
+
+        let (contents, rest) = rest.split_at(length);
+        self.data = rest;
+
+        Ok((*tag, contents))
+    }
+
+    fn read(&mut self, tag: u8) -> Result<&'a [u8], KeyError> {
+        match self.read_any()? {
+            (actual, contents) if actual == tag => Ok(contents),
+            _ => Err(KeyError::Der("unexpected tag")),
+        }
+    }
+
+    fn read_optional(&mut self, tag: u8) -> Result<Option<&'a [u8]>, KeyError> {
+        if self.peek_tag() == Some(tag) {
+            return self.read(tag).map(Some);
+        }
+
+        Ok(None)
+    }
+
+    /// Reads a value that must be the only one left, returning a reader over its contents.
+    fn read_only(mut self, tag: u8) -> Result<DerReader<'a>, KeyError> {
+        let contents = self.read(tag)?;
+        self.finish()?;
+
+        Ok(DerReader::new(contents))
+    }
+
+    /// Reads a bit string of whole bytes.
+    fn read_bit_string(&mut self, tag: u8) -> Result<&'a [u8], KeyError> {
+        match self.read(tag)?.split_first() {
+            Some((0, bytes)) => Ok(bytes),
+            _ => Err(KeyError::Der("invalid bit string")),
+        }
+    }
+
+    /// Reads an `AlgorithmIdentifier`, returning the key algorithm it names.
+    fn read_algorithm(&mut self) -> Result<KeyAlgorithm, KeyError> {
+        let mut identifier = DerReader::new(self.read(SEQUENCE)?);
+
+        let algorithm = match identifier.read(OBJECT_IDENTIFIER)? {
+            ED25519_OID => KeyAlgorithm::Ed25519,
+            SECP256K1_OID => KeyAlgorithm::EcdsaSecp256k1,
+
+            EC_PUBLIC_KEY_OID => {
+                DerReader::new(identifier.read(OBJECT_IDENTIFIER)?).expect_secp256k1()?;
+                KeyAlgorithm::EcdsaSecp256k1
+            }
+
+            oid => return Err(KeyError::UnsupportedAlgorithm(oid_to_string(oid))),
+        };
+
+        identifier.finish()?;
+
+        Ok(algorithm)
+    }
+
+    /// Checks that the remaining contents are the OID of the secp256k1 curve.
+    fn expect_secp256k1(self) -> Result<(), KeyError> {
+        match self.data {
+            SECP256K1_OID => Ok(()),
+            oid => Err(KeyError::UnsupportedAlgorithm(oid_to_string(oid))),
+        }
+    }
+
+    fn finish(&self) -> Result<(), KeyError> {
+        if !self.data.is_empty() {
+            return Err(KeyError::Der("unexpected trailing data"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes an encoded object identifier in dotted form, such as `1.3.101.112`.
+fn oid_to_string(oid: &[u8]) -> String {
+    let mut arcs = Vec::new();
+    let mut arc = 0u64;
+
+    for byte in oid {
+        arc = arc << 7 | u64::from(byte & 0x7f);
+
+        if byte & 0x80 == 0 {
+            arcs.push(arc);
+            arc = 0;
+        }
+    }
+
+    let mut text = String::new();
+
+    if let Some(first) = arcs.first() {
+        let root = (first / 40).min(2);
+        text = format!("{}.{}", root, first - root * 40);
+    }
+
+    for arc in arcs.iter().skip(1) {
+        text += &format!(".{}", arc);
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{oid_to_string, DerReader, SubjectPublicKeyInfo, EC_PUBLIC_KEY_OID, SECP256K1_OID};
+    use crate::key_error::KeyError;
+
+    #[test]
+    fn test_read_length() -> Result<(), KeyError> {
+        let long = [&[0x04, 0x81, 0x80][..], &[7; 0x80]].concat();
+        assert_eq!(DerReader::new(&long).read(0x04)?, &[7; 0x80][..]);
+
+        for invalid in
+            [&[0x04, 0x81, 0x7f][..], &[0x04, 0x82, 0x00, 0x80], &[0x04, 0x80], &[0x04, 0x02, 0x00]]
+        {
+            assert!(matches!(DerReader::new(invalid).read(0x04), Err(KeyError::Der(_))));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oid_to_string() {
+        assert_eq!(oid_to_string(EC_PUBLIC_KEY_OID), "1.2.840.10045.2.1");
+        assert_eq!(oid_to_string(SECP256K1_OID), "1.3.132.0.10");
+        assert_eq!(oid_to_string(&[0x88, 0x37]), "2.999");
+    }
+
+    #[test]
+    fn test_unsupported_algorithm() -> Result<(), KeyError> {
+        // a P-256 key from OpenSSL
+        let p256 = hex::decode("3039301306072a8648ce3d020106082a8648ce3d030107032200020000000000000000000000000000000000000000000000000000000000000001")?;
+
+        assert!(matches!(
+            SubjectPublicKeyInfo::from_der(&p256),
+            Err(KeyError::UnsupportedAlgorithm(curve)) if curve == "1.2.840.10045.3.1.7"
+        ));
+
+        Ok(())
+    }
+}
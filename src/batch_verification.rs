@@ -0,0 +1,220 @@
+use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::{IsIdentity, VartimeMultiscalarMul};
+use rand_core::{OsRng, RngCore};
+
+use crate::public_key::{PublicKey, PublicKeyData};
+use crate::signature::Signature;
+use crate::verification_policy::{challenge, VerificationPolicy};
+use crate::verify_error::VerifyError;
+
+impl PublicKey {
+    /// Verifies many `(message, signature, public key)` entries under `policy`, returning
+    /// the index of every entry that failed, and why, if any did.
+    ///
+    /// Accepts exactly the entries that [`PublicKey::verify_with_policy`] would.
+    /// Under [`VerificationPolicy::Zip215`], Ed25519 signatures are checked together with
+    /// one multiscalar multiplication and, only if that fails, one by one to find the
+    /// failures; every other entry is checked one by one.
+    ///
+    pub fn verify_batch(
+        entries: &[(&[u8], &Signature, &PublicKey)],
+        policy: VerificationPolicy,
+    ) -> Result<(), Vec<(usize, VerifyError)>> {
+        let mut ed25519_entries = Vec::new();
+        let mut failures = Vec::new();
+
+        for (index, (message, signature, key)) in entries.iter().enumerate() {
+            match &key.0 {
+                PublicKeyData::Ed25519(ed25519_key)
+                    if policy == VerificationPolicy::Zip215
+                        && signature.algorithm() == key.algorithm() =>
+                {
+                    ed25519_entries.push((index, *message, signature.to_bytes_raw(), ed25519_key));
+                }
+
+                _ => {
+                    if let Err(error) = key.verify_with_policy(message, signature, policy) {
+                        failures.push((index, error));
+                    }
+                }
+            }
+        }
+
+        if !verify_ed25519_batch(&ed25519_entries) {
+            for (index, message, signature, key) in ed25519_entries {
+                if let Err(error) =
+                    VerificationPolicy::Zip215.verify_ed25519(key, message, &signature)
+                {
+                    failures.push((index, error));
+                }
+            }
+
+            failures.sort_by_key(|(index, _)| *index);
+        }
+
+        if !failures.is_empty() {
+            return Err(failures);
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that `[8]([-Σ zᵢsᵢ]B + Σ [zᵢ]Rᵢ + Σ [zᵢkᵢ]Aᵢ)` is the identity for random 128-bit `zᵢ`,
+/// which holds when every signature is valid under ZIP-215 and, with overwhelming
+/// probability, only then.
+///
+/// `ed25519_dalek::verify_batch` leaves out the cofactor, so it can accept a batch that
+/// fails one by one under every policy; it is not used for that reason.
+///
+fn verify_ed25519_batch(entries: &[(usize, &[u8], [u8; 64], &ed25519_dalek::PublicKey)]) -> bool {
+    let mut random = vec![0; entries.len() * 16];
+    OsRng.fill_bytes(&mut random);
+
+    let mut basepoint_scalar = Scalar::zero();
+    let mut scalars = Vec::with_capacity(entries.len() * 2 + 1);
+    let mut points = Vec::with_capacity(entries.len() * 2 + 1);
+
+    for ((_, message, signature, key), z) in entries.iter().zip(random.chunks_exact(16)) {
+        let r_bytes = CompressedEdwardsY::from_slice(&signature[..32]);
+
+        let r = match r_bytes.decompress() {
+            Some(r) => r,
+            None => return false,
+        };
+
+        let mut s = [0; 32];
+        s.copy_from_slice(&signature[32..]);
+
+        let s = match Scalar::from_canonical_bytes(s) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        // UNWRAP: public keys are checked to be valid points on import
+        let a = CompressedEdwardsY::from_slice(key.as_bytes()).decompress().unwrap();
+
+        let mut z_bytes = [0; 16];
+        z_bytes.copy_from_slice(z);
+        let z = Scalar::from(u128::from_le_bytes(z_bytes));
+
+        basepoint_scalar -= z * s;
+        scalars.push(z);
+        points.push(r);
+        scalars.push(z * challenge(key, message, &r_bytes));
+        points.push(a);
+    }
+
+    scalars.push(basepoint_scalar);
+    points.push(ED25519_BASEPOINT_POINT);
+
+    EdwardsPoint::vartime_multiscalar_mul(scalars, points).mul_by_cofactor().is_identity()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::key_algorithm::KeyAlgorithm;
+    use crate::key_error::KeyError;
+    use crate::private_key::PrivateKey;
+    use crate::public_key::PublicKey;
+    use crate::signature::Signature;
+    use crate::verification_policy::VerificationPolicy;
+    use crate::verify_error::VerifyError;
+
+    const POLICIES: [VerificationPolicy; 3] =
+        [VerificationPolicy::Strict, VerificationPolicy::Zip215, VerificationPolicy::Legacy];
+
+    #[test]
+    fn test_verify_batch() -> Result<(), KeyError> {
+        let keys: Vec<_> = (0..8)
+            .map(|i| if i % 4 == 3 { PrivateKey::generate_ecdsa() } else { PrivateKey::generate() })
+            .collect();
+
+        let messages: Vec<_> = (0..32).map(|i| format!("message {}", i).into_bytes()).collect();
+        let mut signatures: Vec<_> =
+            messages.iter().enumerate().map(|(i, message)| keys[i % 8].sign(message)).collect();
+        let public_keys: Vec<_> = keys.iter().map(PrivateKey::public_key).collect();
+
+        for &policy in &POLICIES {
+            assert_eq!(
+                PublicKey::verify_batch(&entries(&messages, &signatures, &public_keys), policy),
+                Ok(())
+            );
+            assert_eq!(PublicKey::verify_batch(&[], policy), Ok(()));
+        }
+
+        // an Ed25519 signature, an ECDSA signature, an out-of-range scalar and a signature
+        // by a key of another algorithm
+        signatures[5] = tamper(&signatures[5], 32, 0x01);
+        signatures[11] = tamper(&signatures[11], 63, 0x01);
+        signatures[20] = tamper(&signatures[20], 63, 0xff);
+        signatures[26] = keys[3].sign(&messages[26]);
+
+        for &policy in &POLICIES {
+            assert_eq!(
+                PublicKey::verify_batch(&entries(&messages, &signatures, &public_keys), policy),
+                Err(vec![
+                    (5, VerifyError::Mismatch),
+                    (11, VerifyError::Mismatch),
+                    (20, VerifyError::InvalidScalar),
+                    (
+                        26,
+                        VerifyError::AlgorithmMismatch {
+                            expected: KeyAlgorithm::Ed25519,
+                            actual: KeyAlgorithm::EcdsaSecp256k1
+                        }
+                    ),
+                ])
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_batch_zip215() -> Result<(), KeyError> {
+        let public_key = PublicKey::from_bytes(&hex::decode(
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        )?)?;
+
+        // `R` has a component of order 8, which only the cofactor cancels out
+        let torsion: Signature = "ed25519:40a4cf5c611d737a2f1c6fcca84d9cabfed80987f186a5a6c57e629bff1af27fbd0f9e4553a678cb61530032758f2221f00f830919cd5ffee6898b54d292cf09".parse()?;
+
+        let entries = vec![(&b"hello, world"[..], &torsion, &public_key); 4];
+        assert_eq!(PublicKey::verify_batch(&entries, VerificationPolicy::Zip215), Ok(()));
+
+        // the other policies reject it, one by one as in a batch
+        for &policy in &[VerificationPolicy::Strict, VerificationPolicy::Legacy] {
+            assert_eq!(
+                public_key.verify_with_policy(b"hello, world", &torsion, policy),
+                Err(VerifyError::Mismatch)
+            );
+            assert_eq!(
+                PublicKey::verify_batch(&entries[..2], policy),
+                Err(vec![(0, VerifyError::Mismatch), (1, VerifyError::Mismatch)])
+            );
+        }
+
+        Ok(())
+    }
+
+    fn entries<'a>(
+        messages: &'a [Vec<u8>],
+        signatures: &'a [Signature],
+        public_keys: &'a [PublicKey],
+    ) -> Vec<(&'a [u8], &'a Signature, &'a PublicKey)> {
+        (0..messages.len())
+            .map(|i| (&messages[i][..], &signatures[i], &public_keys[i % public_keys.len()]))
+            .collect()
+    }
+
+    /// Returns `signature` with `mask` flipped into its byte at `index`.
+    fn tamper(signature: &Signature, index: usize, mask: u8) -> Signature {
+        let mut bytes = signature.to_bytes_raw();
+        bytes[index] ^= mask;
+
+        Signature::from_bytes_raw(signature.algorithm(), &bytes).unwrap()
+    }
+}
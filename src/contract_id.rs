@@ -0,0 +1,178 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::evm_address::EvmAddress;
+use crate::key_error::KeyError;
+use crate::proto;
+
+/// The ID of a smart contract on the Hedera™ Network, in `shard.realm.num` form.
+///
+/// A contract can instead be identified by its EVM address, in which case `num` is unused
+/// and the ID is written as `shard.realm.<address>`.
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ContractId {
+    pub shard: u64,
+    pub realm: u64,
+    pub num: u64,
+    pub evm_address: Option<EvmAddress>,
+}
+
+impl ContractId {
+    /// Returns a contract ID from its `shard.realm.num` parts.
+    pub fn new(shard: u64, realm: u64, num: u64) -> Self {
+        Self { shard, realm, num, evm_address: None }
+    }
+
+    /// Returns a contract ID that identifies the contract by its EVM address.
+    pub fn from_evm_address(shard: u64, realm: u64, evm_address: EvmAddress) -> Self {
+        Self { shard, realm, num: 0, evm_address: Some(evm_address) }
+    }
+
+    pub(crate) fn to_protobuf(self) -> proto::ContractId {
+        let contract = match self.evm_address {
+            Some(address) => proto::contract_id::Contract::EvmAddress(address.to_bytes().to_vec()),
+            None => proto::contract_id::Contract::ContractNum(self.num as i64),
+        };
+
+        proto::ContractId {
+            shard_num: self.shard as i64,
+            realm_num: self.realm as i64,
+            contract: Some(contract),
+        }
+    }
+
+    pub(crate) fn from_protobuf(id: proto::ContractId) -> Result<Self, KeyError> {
+        let invalid = || KeyError::ContractId(format!("{:?}", id));
+
+        let shard = u64::try_from(id.shard_num).map_err(|_| invalid())?;
+        let realm = u64::try_from(id.realm_num).map_err(|_| invalid())?;
+
+        match &id.contract {
+            Some(proto::contract_id::Contract::EvmAddress(address)) => {
+                Ok(Self::from_evm_address(shard, realm, EvmAddress::from_bytes(address)?))
+            }
+
+            Some(proto::contract_id::Contract::ContractNum(num)) => {
+                let num = u64::try_from(*num).map_err(|_| invalid())?;
+
+                Ok(Self::new(shard, realm, num))
+            }
+
+            None => Err(invalid()),
+        }
+    }
+}
+
+impl Display for ContractId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.evm_address {
+            Some(address) => write!(f, "{}.{}.{}", self.shard, self.realm, hex::encode(address)),
+            None => write!(f, "{}.{}.{}", self.shard, self.realm, self.num),
+        }
+    }
+}
+
+impl FromStr for ContractId {
+    type Err = KeyError;
+
+    fn from_str(text: &str) -> Result<Self, KeyError> {
+        let invalid = || KeyError::ContractId(text.to_string());
+
+        let mut parts = text.splitn(3, '.');
+        let mut next = || parts.next().ok_or_else(invalid);
+
+        let shard = next()?.parse().map_err(|_| invalid())?;
+        let realm = next()?.parse().map_err(|_| invalid())?;
+        let last = next()?;
+
+        if let Ok(num) = last.parse() {
+            return Ok(Self::new(shard, realm, num));
+        }
+
+        let address = EvmAddress::from_str(last).map_err(|_| invalid())?;
+
+        Ok(Self::from_evm_address(shard, realm, address))
+    }
+}
+
+/// Serializes as a `shard.realm.num` string, like `Display`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ContractId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes from any string accepted by `FromStr`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ContractId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::ContractId;
+    use crate::{proto, KeyError};
+
+    #[test]
+    fn test_to_from_string() -> Result<(), KeyError> {
+        let id = ContractId::from_str("0.0.1234")?;
+
+        assert_eq!(id, ContractId::new(0, 0, 1234));
+        assert_eq!(id.to_string(), "0.0.1234");
+
+        let address = "1.2.7e5f4552091a69125d5dfcb7b8c2659029395bdf";
+        let id = ContractId::from_str(address)?;
+
+        assert_eq!((id.shard, id.realm), (1, 2));
+        assert!(id.evm_address.is_some());
+        assert_eq!(id.to_string(), address);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_string() {
+        for text in &["", "0.0", "0.0.x", "a.0.1", "0.0.1.2", "0.0.7e5f45"] {
+            assert!(matches!(ContractId::from_str(text), Err(KeyError::ContractId(_))), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_protobuf_round_trip() -> Result<(), KeyError> {
+        for text in &["0.0.1234", "3.4.7e5f4552091a69125d5dfcb7b8c2659029395bdf"] {
+            let id = ContractId::from_str(text)?;
+
+            assert_eq!(ContractId::from_protobuf(id.to_protobuf())?, id);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_protobuf() {
+        let negative = [(-1, 0, 1), (0, -1, 1), (0, 0, -1)];
+
+        for &(shard_num, realm_num, num) in &negative {
+            let id = proto::ContractId {
+                shard_num,
+                realm_num,
+                contract: Some(proto::contract_id::Contract::ContractNum(num)),
+            };
+
+            assert!(matches!(ContractId::from_protobuf(id), Err(KeyError::ContractId(_))));
+        }
+
+        let id = proto::ContractId { shard_num: 0, realm_num: 0, contract: None };
+        assert!(matches!(ContractId::from_protobuf(id), Err(KeyError::ContractId(_))));
+    }
+}
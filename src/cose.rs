@@ -0,0 +1,380 @@
+use std::convert::TryFrom;
+use std::mem;
+
+use ciborium::value::Value;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::key_algorithm::KeyAlgorithm;
+use crate::key_error::KeyError;
+use crate::private_key::PrivateKey;
+use crate::public_key::PublicKey;
+
+// the labels and values of RFC 9052/9053 and, for secp256k1, RFC 8812
+const KEY_TYPE: i64 = 1;
+const CURVE: i64 = -1;
+const X: i64 = -2;
+const Y: i64 = -3;
+const D: i64 = -4;
+
+const KEY_TYPE_OKP: i64 = 1;
+const KEY_TYPE_EC2: i64 = 2;
+const CURVE_ED25519: i64 = 6;
+const CURVE_SECP256K1: i64 = 8;
+
+const HEADER_ALGORITHM: i64 = 1;
+const HEADER_CRITICAL: i64 = 2;
+const ALGORITHM_EDDSA: i64 = -8;
+
+const COSE_SIGN1_TAG: u64 = 18;
+
+impl PublicKey {
+    /// Returns a public key from its CBOR-encoded `COSE_Key`, either an `OKP` Ed25519 key
+    /// or an `EC2` secp256k1 key.
+    ///
+    /// Any private key (`d`) in the `COSE_Key` is ignored.
+    ///
+    pub fn from_cose_key(data: &[u8]) -> Result<PublicKey, KeyError> {
+        public_key_from_cose_key(&into_map(decode(data)?)?)
+    }
+
+    /// Returns this public key as a CBOR-encoded `COSE_Key`.
+    pub fn to_cose_key(&self) -> Vec<u8> {
+        encode(&Value::Map(cose_key_entries(self)))
+    }
+
+    /// Checks a CBOR-encoded `COSE_Sign1` message signed with `EdDSA` by this key,
+    /// returning its payload.
+    ///
+    /// The message may be tagged or untagged. Any other algorithm and any critical
+    /// header are rejected, as are messages with a detached payload.
+    ///
+    pub fn verify_cose(&self, data: &[u8]) -> Result<Vec<u8>, KeyError> {
+        let message = match decode(data)? {
+            Value::Tag(COSE_SIGN1_TAG, message) => *message,
+            message => message,
+        };
+
+        let parts = match message {
+            Value::Array(parts) if parts.len() == 4 => parts,
+            _ => return Err(KeyError::Cose("expected a COSE_Sign1 message")),
+        };
+
+        let (protected, payload, signature) = match (&parts[0], &parts[2], &parts[3]) {
+            (Value::Bytes(protected), Value::Bytes(payload), Value::Bytes(signature)) => {
+                (protected, payload, signature)
+            }
+
+            (_, Value::Null, _) => return Err(KeyError::Cose("detached payloads are unsupported")),
+            _ => return Err(KeyError::Cose("expected a COSE_Sign1 message")),
+        };
+
+        let headers = into_map(decode(protected)?)?;
+
+        if lookup(&headers, HEADER_CRITICAL).is_some() {
+            return Err(KeyError::Cose("unsupported critical header"));
+        }
+
+        if lookup(&headers, HEADER_ALGORITHM).and_then(as_integer) != Some(ALGORITHM_EDDSA)
+            || !self.is_ed25519()
+        {
+            return Err(KeyError::Cose("unsupported algorithm"));
+        }
+
+        if !self.verify_raw(&signature_structure(protected, payload), signature) {
+            return Err(KeyError::Cose("signature does not match"));
+        }
+
+        Ok(payload.clone())
+    }
+}
+
+impl PrivateKey {
+    /// Returns a private key from its CBOR-encoded `COSE_Key`, which must include
+    /// the private key (`d`).
+    ///
+    /// The public key in the `COSE_Key`, if any, must match the private key.
+    ///
+    pub fn from_cose_key(data: &[u8]) -> Result<PrivateKey, KeyError> {
+        let mut entries = into_map(decode(data)?)?;
+
+        let d = match entries.iter_mut().find(|(label, _)| as_integer(label) == Some(D)) {
+            Some((_, Value::Bytes(d))) => Zeroizing::new(mem::take(d)),
+            _ => return Err(KeyError::Cose("no private key")),
+        };
+
+        let key_type = lookup(&entries, KEY_TYPE).and_then(as_integer);
+        let curve = lookup(&entries, CURVE).and_then(as_integer);
+
+        let key = match (key_type, curve, d.len()) {
+            (Some(KEY_TYPE_OKP), Some(CURVE_ED25519), 32) => PrivateKey::from_bytes_ed25519(&d)?,
+            (Some(KEY_TYPE_EC2), Some(CURVE_SECP256K1), 32) => PrivateKey::from_bytes_ecdsa(&d)?,
+            (Some(KEY_TYPE_OKP), Some(CURVE_ED25519), len)
+            | (Some(KEY_TYPE_EC2), Some(CURVE_SECP256K1), len) => {
+                return Err(KeyError::Length(len))
+            }
+            _ => return Err(KeyError::Cose("unsupported key type or curve")),
+        };
+
+        // a private `COSE_Key` may leave out its public key
+        if lookup(&entries, X).is_some() && public_key_from_cose_key(&entries)? != key.public_key()
+        {
+            return Err(KeyError::PublicKeyMismatch);
+        }
+
+        Ok(key)
+    }
+
+    /// Returns this private key as a CBOR-encoded `COSE_Key`, including the private key (`d`).
+    ///
+    /// [`PublicKey::to_cose_key`] writes the same map without `d`, for sharing the key.
+    ///
+    pub fn to_cose_key_secret(&self) -> Vec<u8> {
+        let mut entries = cose_key_entries(&self.public_key());
+        entries.push((D.into(), Value::Bytes(Zeroizing::new(self.to_bytes_raw()).to_vec())));
+
+        let mut key = Value::Map(entries);
+        let data = encode(&key);
+
+        // wipe the copy of the private key in the map
+        if let Value::Map(entries) = &mut key {
+            if let Some((_, Value::Bytes(d))) = entries.last_mut() {
+                d.zeroize();
+            }
+        }
+
+        data
+    }
+
+    /// Signs `payload` as a tagged, CBOR-encoded `COSE_Sign1` message with `EdDSA`.
+    ///
+    /// ECDSA(secp256k1) keys sign a Keccak-256 hash, which no COSE algorithm identifier
+    /// names, and return [`KeyError::AlgorithmMismatch`].
+    ///
+    pub fn sign_cose(&self, payload: &[u8]) -> Result<Vec<u8>, KeyError> {
+        if !self.is_ed25519() {
+            return Err(KeyError::AlgorithmMismatch {
+                expected: KeyAlgorithm::Ed25519,
+                actual: self.algorithm(),
+            });
+        }
+
+        let protected =
+            encode(&Value::Map(vec![(HEADER_ALGORITHM.into(), ALGORITHM_EDDSA.into())]));
+        let signature = self.sign(&signature_structure(&protected, payload));
+
+        Ok(encode(&Value::Tag(
+            COSE_SIGN1_TAG,
+            Box::new(Value::Array(vec![
+                Value::Bytes(protected),
+                Value::Map(Vec::new()),
+                Value::Bytes(payload.to_vec()),
+                Value::Bytes(signature.as_ref().to_vec()),
+            ])),
+        )))
+    }
+}
+
+fn cose_key_entries(key: &PublicKey) -> Vec<(Value, Value)> {
+    match key.to_affine_coordinates() {
+        None => vec![
+            (KEY_TYPE.into(), KEY_TYPE_OKP.into()),
+            (CURVE.into(), CURVE_ED25519.into()),
+            (X.into(), Value::Bytes(key.to_bytes_raw())),
+        ],
+
+        Some((x, y)) => vec![
+            (KEY_TYPE.into(), KEY_TYPE_EC2.into()),
+            (CURVE.into(), CURVE_SECP256K1.into()),
+            (X.into(), Value::Bytes(x.to_vec())),
+            (Y.into(), Value::Bytes(y.to_vec())),
+        ],
+    }
+}
+
+fn public_key_from_cose_key(entries: &[(Value, Value)]) -> Result<PublicKey, KeyError> {
+    let key_type = lookup(entries, KEY_TYPE).and_then(as_integer);
+    let curve = lookup(entries, CURVE).and_then(as_integer);
+    let x = lookup(entries, X).and_then(Value::as_bytes);
+    let y = lookup(entries, Y).and_then(Value::as_bytes);
+
+    match (key_type, curve, x, y) {
+        (Some(KEY_TYPE_OKP), Some(CURVE_ED25519), Some(x), None) => {
+            PublicKey::from_bytes_raw(KeyAlgorithm::Ed25519, x)
+        }
+
+        (Some(KEY_TYPE_EC2), Some(CURVE_SECP256K1), Some(x), Some(y)) => {
+            if x.len() != 32 || y.len() != 32 {
+                return Err(KeyError::Cose("invalid coordinate length"));
+            }
+
+            PublicKey::from_bytes_raw(
+                KeyAlgorithm::EcdsaSecp256k1,
+                &[&[4], &x[..], &y[..]].concat(),
+            )
+        }
+
+        (Some(_), Some(_), Some(_), _) => Err(KeyError::Cose("unsupported key type or curve")),
+        _ => Err(KeyError::Cose("missing key parameters")),
+    }
+}
+
+/// Returns the `Sig_structure` that a `COSE_Sign1` message signs, with no external data.
+fn signature_structure(protected: &[u8], payload: &[u8]) -> Vec<u8> {
+    encode(&Value::Array(vec![
+        Value::Text("Signature1".to_owned()),
+        Value::Bytes(protected.to_vec()),
+        Value::Bytes(Vec::new()),
+        Value::Bytes(payload.to_vec()),
+    ]))
+}
+
+fn lookup(entries: &[(Value, Value)], label: i64) -> Option<&Value> {
+    entries.iter().find(|(key, _)| as_integer(key) == Some(label)).map(|(_, value)| value)
+}
+
+fn as_integer(value: &Value) -> Option<i64> {
+    value.as_integer().and_then(|integer| i64::try_from(integer).ok())
+}
+
+fn into_map(value: Value) -> Result<Vec<(Value, Value)>, KeyError> {
+    match value {
+        Value::Map(entries) => Ok(entries),
+        _ => Err(KeyError::Cose("expected a map")),
+    }
+}
+
+fn encode(value: &Value) -> Vec<u8> {
+    let mut data = Vec::new();
+
+    // UNWRAP: writing to a `Vec` cannot fail
+    ciborium::ser::into_writer(value, &mut data).unwrap();
+
+    data
+}
+
+fn decode(mut data: &[u8]) -> Result<Value, KeyError> {
+    let value = ciborium::de::from_reader(&mut data).map_err(|_| KeyError::Cose("invalid CBOR"))?;
+
+    if !data.is_empty() {
+        return Err(KeyError::Cose("unexpected trailing data"));
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::key_error::KeyError;
+    use crate::private_key::PrivateKey;
+    use crate::public_key::PublicKey;
+
+    // the first test vector of RFC 8032
+    const ED25519_PRIVATE_KEY: &str =
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    const ED25519_COSE_KEY: &str =
+        "a301012006215820d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+    const ECDSA_PRIVATE_KEY_STR: &str = "3030020100300706052b8104000a042204200000000000000000000000000000000000000000000000000000000000000001";
+    const ECDSA_COSE_KEY: &str = "a40102200821582079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798225820483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+    const PAYLOAD: &[u8] = b"This is the content.";
+    const COSE_SIGN1: &str = "d28443a10127a054546869732069732074686520636f6e74656e742e58406354488f9f290e36cd80e23762e664a5cb03e4267c66a8cffaef7c66d89a40bf2cbb8222432a08e5ee410d8b540c6931d26fb6af673f7e2100655d8bae765c04";
+
+    #[test]
+    fn test_ed25519_cose_key() -> Result<(), KeyError> {
+        let private_key = PrivateKey::from_bytes(&hex::decode(ED25519_PRIVATE_KEY)?)?;
+        let public_key = PublicKey::from_cose_key(&hex::decode(ED25519_COSE_KEY)?)?;
+
+        assert_eq!(private_key.public_key(), public_key);
+        assert_eq!(hex::encode(public_key.to_cose_key()), ED25519_COSE_KEY);
+
+        let secret = private_key.to_cose_key_secret();
+        assert_eq!(
+            hex::encode(&secret),
+            format!("a4{}235820{}", &ED25519_COSE_KEY[2..], ED25519_PRIVATE_KEY)
+        );
+        assert_eq!(PrivateKey::from_cose_key(&secret)?, private_key);
+        assert_eq!(PublicKey::from_cose_key(&secret)?, public_key);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ecdsa_cose_key() -> Result<(), KeyError> {
+        let private_key: PrivateKey = ECDSA_PRIVATE_KEY_STR.parse()?;
+        let public_key = PublicKey::from_cose_key(&hex::decode(ECDSA_COSE_KEY)?)?;
+
+        assert_eq!(private_key.public_key(), public_key);
+        assert_eq!(hex::encode(public_key.to_cose_key()), ECDSA_COSE_KEY);
+        assert_eq!(PrivateKey::from_cose_key(&private_key.to_cose_key_secret())?, private_key);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cose_key_invalid() -> Result<(), KeyError> {
+        let public_key = hex::decode(ED25519_COSE_KEY)?;
+        assert!(matches!(PrivateKey::from_cose_key(&public_key), Err(KeyError::Cose(_))));
+
+        // a private key with the public key of another
+        let mismatched = hex::decode(format!(
+            "a4{}23582000{}",
+            &ED25519_COSE_KEY[2..],
+            &ED25519_PRIVATE_KEY[2..]
+        ))?;
+        assert!(matches!(PrivateKey::from_cose_key(&mismatched), Err(KeyError::PublicKeyMismatch)));
+
+        // an Ed25519 `COSE_Key` with a y-coordinate
+        let y = hex::decode(format!("a4{}225820{}", &ED25519_COSE_KEY[2..], "00".repeat(32)))?;
+        assert!(matches!(PublicKey::from_cose_key(&y), Err(KeyError::Cose(_))));
+
+        // a P-256 `COSE_Key`
+        let p256 = hex::decode(ECDSA_COSE_KEY.replacen("2008", "2001", 1))?;
+        assert!(matches!(PublicKey::from_cose_key(&p256), Err(KeyError::Cose(_))));
+
+        let trailing = hex::decode(format!("{}00", ED25519_COSE_KEY))?;
+        assert!(matches!(PublicKey::from_cose_key(&trailing), Err(KeyError::Cose(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cose_sign1() -> Result<(), KeyError> {
+        let private_key = PrivateKey::from_bytes(&hex::decode(ED25519_PRIVATE_KEY)?)?;
+        let public_key = private_key.public_key();
+
+        let message = private_key.sign_cose(PAYLOAD)?;
+        assert_eq!(hex::encode(&message), COSE_SIGN1);
+        assert_eq!(public_key.verify_cose(&message)?, PAYLOAD);
+
+        // the untagged message
+        assert_eq!(public_key.verify_cose(&message[1..])?, PAYLOAD);
+
+        let other = PrivateKey::generate().public_key();
+        assert!(matches!(other.verify_cose(&message), Err(KeyError::Cose(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cose_sign1_invalid() -> Result<(), KeyError> {
+        let private_key = PrivateKey::from_bytes(&hex::decode(ED25519_PRIVATE_KEY)?)?;
+        let public_key = private_key.public_key();
+
+        let ecdsa_key = PrivateKey::generate_ecdsa();
+        assert!(matches!(ecdsa_key.sign_cose(PAYLOAD), Err(KeyError::AlgorithmMismatch { .. })));
+
+        let signature = &COSE_SIGN1[COSE_SIGN1.len() - 132..];
+        let detached = hex::decode(format!("d28443a10127a0f6{}", signature))?;
+        assert!(matches!(public_key.verify_cose(&detached), Err(KeyError::Cose(_))));
+
+        // `{1: -7}`, ES256
+        let es256 = hex::decode(COSE_SIGN1.replacen("a10127", "a10126", 1))?;
+        assert!(matches!(public_key.verify_cose(&es256), Err(KeyError::Cose(_))));
+
+        let tampered = hex::decode(COSE_SIGN1.replacen("636f6e74656e74", "636f6e74656e75", 1))?;
+        assert!(matches!(public_key.verify_cose(&tampered), Err(KeyError::Cose(_))));
+
+        Ok(())
+    }
+}
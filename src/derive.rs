@@ -3,6 +3,7 @@ use std::cell::RefCell;
 use hmac::Hmac;
 use pbkdf2::pbkdf2;
 use sha2::Sha512;
+use zeroize::Zeroize;
 
 pub(crate) fn legacy(seed: &[u8; 32], index: i32) -> [u8; 32] {
     const SALT: [u8; 1] = [0xff];
@@ -22,6 +23,7 @@ pub(crate) fn legacy(seed: &[u8; 32], index: i32) -> [u8; 32] {
         let mut derived_key: [u8; 32] = [0; 32];
 
         pbkdf2::<Hmac<Sha512>>(&*buf, &SALT, 2048, &mut derived_key);
+        buf.zeroize();
 
         derived_key
     })
@@ -1,5 +1,3 @@
-use std::convert::TryInto;
-
 use num_bigint::ToBigInt;
 use sha2::{Digest, Sha256};
 
@@ -7,22 +5,34 @@ use crate::bip39_words::BIP39_WORDS;
 use crate::legacy_words::LEGACY_WORDS;
 use crate::mnemonic_error::MnemonicError;
 
-pub fn legacy_1(words: &[String]) -> ([u8; 32], u8) {
+pub fn legacy_1(words: &[String]) -> Result<([u8; 32], u8), MnemonicError> {
     let indices = words
-        .into_iter()
-        .filter_map(|word| LEGACY_WORDS.binary_search(&&word.to_lowercase()[..]).ok())
-        .collect::<Vec<_>>();
+        .iter()
+        .enumerate()
+        .map(|(word_index, word)| {
+            LEGACY_WORDS.binary_search(&&word.to_lowercase()[..]).map_err(|_| {
+                MnemonicError::WordNotFound { index: word_index, word: word.to_string() }
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
     let data = convert_radix(&indices, LEGACY_WORDS.len() as u16, 256);
-    let checksum = data[data.len() - 1];
+    let checksum = data[32];
 
-    let mut result = Vec::new();
-    for i in 0..data.len() - 1 {
-        result.push(data[i] ^ checksum);
+    let mut result = [0; 32];
+    for i in 0..result.len() {
+        result[i] = data[i] ^ checksum;
     }
-    return (result.try_into().unwrap(), checksum);
+
+    Ok((result, checksum))
 }
 
 pub fn legacy_2(words: &[String]) -> Result<[u8; 32], MnemonicError> {
+    // the entropy is read from the first 256 bits
+    if words.len() != 24 {
+        return Err(MnemonicError::UnsupportedLength(words.len()));
+    }
+
     let concat_bits_len = words.len() * 11;
     let mut concat_bits = vec![false; words.len() * 11];
 
@@ -36,9 +46,9 @@ pub fn legacy_2(words: &[String]) -> Result<[u8; 32], MnemonicError> {
         }
     }
 
-    let check_sum_bits_len = concat_bits_len as u8 / 33;
-    let entropy_bits_len = concat_bits_len as u8 - check_sum_bits_len;
-    let mut entropy = vec![0; 32];
+    let check_sum_bits_len = concat_bits_len / 33;
+    let entropy_bits_len = concat_bits_len - check_sum_bits_len;
+    let mut entropy = [0; 32];
 
     for i in 0..entropy.len() {
         for j in 0..8 {
@@ -51,12 +61,13 @@ pub fn legacy_2(words: &[String]) -> Result<[u8; 32], MnemonicError> {
     let hash = Sha256::digest(&entropy);
     let hash_bits = bytes_to_bits(&hash);
 
-    for i in 0..check_sum_bits_len as usize {
-        if concat_bits[entropy_bits_len as usize + i] != hash_bits[i] {
+    for i in 0..check_sum_bits_len {
+        if concat_bits[entropy_bits_len + i] != hash_bits[i] {
             return Err(MnemonicError::ChecksumMismatch);
         }
     }
-    return Ok(entropy.try_into().unwrap());
+
+    Ok(entropy)
 }
 
 pub fn convert_radix(nums: &[usize], from_radix: u16, to_radix: u16) -> [u8; 33] {
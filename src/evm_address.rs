@@ -0,0 +1,159 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use sha3::{Digest, Keccak256};
+
+use crate::key_error::KeyError;
+
+/// A 20-byte EVM address.
+///
+/// On the Hedera™ Network an EVM address can be used as an account alias (HIP-583);
+/// sending HBAR to an unused alias automatically creates the account.
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct EvmAddress(pub(crate) [u8; 20]);
+
+impl EvmAddress {
+    /// Returns an EVM address from its 20 raw bytes.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, KeyError> {
+        let bytes =
+            <[u8; 20]>::try_from(data).map_err(|_| KeyError::EvmAddressLength(data.len()))?;
+
+        Ok(Self(bytes))
+    }
+
+    /// Derives the EVM address of an uncompressed SEC1 secp256k1 point: the last 20 bytes
+    /// of the Keccak-256 hash of the point, without its `0x04` tag.
+    pub(crate) fn from_uncompressed_point(point: &[u8]) -> Self {
+        let hash = Keccak256::digest(&point[1..]);
+
+        // UNWRAP: a Keccak-256 hash is always 32 bytes
+        Self(<[u8; 20]>::try_from(&hash[12..]).unwrap())
+    }
+
+    /// Returns the raw 20 bytes of this address, which are also its alias bytes.
+    pub fn to_bytes(&self) -> [u8; 20] {
+        self.0
+    }
+
+    /// Returns the EIP-55 mixed-case checksum encoding of this address, without the `0x` prefix.
+    pub fn to_checksum_hex(&self) -> String {
+        let lower = hex::encode(self.0);
+        let hash = Keccak256::digest(lower.as_bytes());
+
+        lower
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let nibble = if i % 2 == 0 { hash[i / 2] >> 4 } else { hash[i / 2] & 0x0f };
+
+                if nibble >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            })
+            .collect()
+    }
+}
+
+impl AsRef<[u8]> for EvmAddress {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Display for EvmAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", self.to_checksum_hex())
+    }
+}
+
+impl FromStr for EvmAddress {
+    type Err = KeyError;
+
+    /// Parses an EVM address, with or without the `0x` prefix.
+    ///
+    /// All-lowercase and all-uppercase addresses carry no checksum and are accepted as-is;
+    /// mixed-case addresses must match their EIP-55 checksum.
+    ///
+    fn from_str(text: &str) -> Result<Self, KeyError> {
+        let text = text.strip_prefix("0x").unwrap_or(text);
+        let address = Self::from_bytes(&hex::decode(text)?)?;
+
+        let has_lower = text.chars().any(|c| c.is_ascii_lowercase());
+        let has_upper = text.chars().any(|c| c.is_ascii_uppercase());
+
+        if has_lower && has_upper && text != address.to_checksum_hex() {
+            return Err(KeyError::EvmAddressChecksum);
+        }
+
+        Ok(address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::EvmAddress;
+    use crate::{KeyError, PrivateKey};
+
+    // test vectors from EIP-55
+    const CHECKSUM_ADDRESSES: &[&str] = &[
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ];
+
+    #[test]
+    fn test_checksum_round_trip() -> Result<(), KeyError> {
+        for text in CHECKSUM_ADDRESSES {
+            assert_eq!(EvmAddress::from_str(text)?.to_string(), *text);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unchecksummed() -> Result<(), KeyError> {
+        let lower = EvmAddress::from_str("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed")?;
+        let upper = EvmAddress::from_str("0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED")?;
+
+        assert_eq!(lower, upper);
+        assert_eq!(lower.to_string(), CHECKSUM_ADDRESSES[0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bad_checksum() {
+        assert!(matches!(
+            EvmAddress::from_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+            Err(KeyError::EvmAddressChecksum)
+        ));
+        assert!(matches!(EvmAddress::from_str("0x5aAeb605"), Err(KeyError::EvmAddressLength(4))));
+    }
+
+    #[test]
+    fn test_public_key_to_evm_address() -> Result<(), KeyError> {
+        let private_key = PrivateKey::from_bytes_ecdsa(&hex::decode(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        )?)?;
+
+        assert_eq!(
+            private_key.public_key().to_evm_address()?.to_string(),
+            "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf"
+        );
+
+        assert!(matches!(
+            PrivateKey::generate().public_key().to_evm_address(),
+            Err(KeyError::AlgorithmMismatch { .. })
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+use std::fmt;
+
+/// A borrowed secret that is written out in full by `Display` (and `Debug`).
+///
+/// Private keys and mnemonics redact themselves when formatted, so that they
+/// cannot end up in a log line by accident. Use [`PrivateKey::expose_secret`](crate::PrivateKey::expose_secret)
+/// or [`Mnemonic::expose_secret`](crate::Mnemonic::expose_secret) where the secret is really needed.
+///
+pub struct ExposedSecret<'a, T>(pub(crate) &'a T);
+
+impl<T> fmt::Debug for ExposedSecret<'_, T>
+where
+    Self: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
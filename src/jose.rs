@@ -0,0 +1,308 @@
+use std::borrow::Cow;
+
+use base64ct::{Base64UrlUnpadded, Encoding};
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::key_algorithm::KeyAlgorithm;
+use crate::key_error::KeyError;
+use crate::private_key::PrivateKey;
+use crate::public_key::PublicKey;
+
+// the protected header of every JWS written here
+const JWS_HEADER: &str = r#"{"alg":"EdDSA"}"#;
+
+/// A JSON Web Key (RFC 7517), as an `OKP` Ed25519 key (RFC 8037)
+/// or an `EC` secp256k1 key (RFC 8812).
+#[derive(Deserialize, Serialize)]
+struct Jwk<'a> {
+    kty: Cow<'a, str>,
+    crv: Cow<'a, str>,
+    x: Cow<'a, str>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    y: Option<Cow<'a, str>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    d: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct JwsHeader {
+    alg: String,
+
+    #[serde(default)]
+    crit: Option<serde_json::Value>,
+}
+
+impl Drop for Jwk<'_> {
+    fn drop(&mut self) {
+        if let Some(d) = &mut self.d {
+            d.zeroize();
+        }
+    }
+}
+
+impl<'a> Jwk<'a> {
+    fn from_public_key(key: &PublicKey) -> Self {
+        match key.to_affine_coordinates() {
+            None => Jwk {
+                kty: "OKP".into(),
+                crv: "Ed25519".into(),
+                x: encode(&key.to_bytes_raw()).into(),
+                y: None,
+                d: None,
+            },
+
+            Some((x, y)) => Jwk {
+                kty: "EC".into(),
+                crv: "secp256k1".into(),
+                x: encode(&x).into(),
+                y: Some(encode(&y).into()),
+                d: None,
+            },
+        }
+    }
+
+    fn to_json(&self) -> String {
+        // UNWRAP: serializing a struct of strings cannot fail
+        serde_json::to_string(self).unwrap()
+    }
+
+    fn to_public_key(&self) -> Result<PublicKey, KeyError> {
+        match (&*self.kty, &*self.crv, &self.y) {
+            ("OKP", "Ed25519", None) => {
+                PublicKey::from_bytes_raw(KeyAlgorithm::Ed25519, &decode(&self.x)?)
+            }
+
+            ("EC", "secp256k1", Some(y)) => {
+                let (x, y) = (decode(&self.x)?, decode(y)?);
+
+                if x.len() != 32 || y.len() != 32 {
+                    return Err(KeyError::Jose("invalid coordinate length"));
+                }
+
+                PublicKey::from_bytes_raw(KeyAlgorithm::EcdsaSecp256k1, &[&[4], &*x, &*y].concat())
+            }
+
+            (kty, crv, _) => Err(KeyError::UnsupportedAlgorithm(format!("{} {}", kty, crv))),
+        }
+    }
+}
+
+impl PublicKey {
+    /// Returns a public key from a JSON Web Key, either an `OKP` Ed25519 key
+    /// or an `EC` secp256k1 key.
+    ///
+    /// Any private key (`d`) in the JWK is ignored.
+    ///
+    pub fn from_jwk(json: &str) -> Result<PublicKey, KeyError> {
+        serde_json::from_str::<Jwk<'_>>(json)?.to_public_key()
+    }
+
+    /// Returns this public key as a JSON Web Key.
+    pub fn to_jwk(&self) -> String {
+        Jwk::from_public_key(self).to_json()
+    }
+
+    /// Checks a compact JWS signed with `alg: EdDSA` (RFC 8037) by this key,
+    /// returning its payload.
+    ///
+    /// Any other algorithm, including `none`, is rejected, as is any critical header.
+    ///
+    pub fn verify_jws(&self, jws: &str) -> Result<Vec<u8>, KeyError> {
+        let mut parts = jws.split('.');
+
+        let (header, payload, signature) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(header), Some(payload), Some(signature)) if parts.next().is_none() => {
+                (header, payload, signature)
+            }
+
+            _ => return Err(KeyError::Jose("expected three parts")),
+        };
+
+        let parsed_header: JwsHeader = serde_json::from_slice(&decode(header)?)?;
+
+        if parsed_header.alg != "EdDSA" || !self.is_ed25519() {
+            return Err(KeyError::Jose("unsupported algorithm"));
+        }
+
+        if parsed_header.crit.is_some() {
+            return Err(KeyError::Jose("unsupported critical header"));
+        }
+
+        let signing_input = &jws[..header.len() + 1 + payload.len()];
+
+        if !self.verify_raw(signing_input.as_bytes(), &decode(signature)?) {
+            return Err(KeyError::Jose("signature does not match"));
+        }
+
+        decode(payload)
+    }
+}
+
+impl PrivateKey {
+    /// Returns a private key from a JSON Web Key with a private key (`d`).
+    ///
+    /// The public key in the JWK must match the private key.
+    ///
+    pub fn from_jwk(json: &str) -> Result<PrivateKey, KeyError> {
+        let jwk: Jwk<'_> = serde_json::from_str(json)?;
+        let public_key = jwk.to_public_key()?;
+
+        let d = Zeroizing::new(decode(jwk.d.as_deref().ok_or(KeyError::Jose("no private key"))?)?);
+
+        let key = match (public_key.algorithm(), d.len()) {
+            (KeyAlgorithm::Ed25519, 32) => PrivateKey::from_bytes_ed25519(&d)?,
+            (KeyAlgorithm::EcdsaSecp256k1, 32) => PrivateKey::from_bytes_ecdsa(&d)?,
+            (_, len) => return Err(KeyError::Length(len)),
+        };
+
+        if key.public_key() != public_key {
+            return Err(KeyError::PublicKeyMismatch);
+        }
+
+        Ok(key)
+    }
+
+    /// Returns this private key as a JSON Web Key, including the private key (`d`).
+    ///
+    /// This is the form [`PrivateKey::from_jwk`] reads; share [`PublicKey::to_jwk`] instead.
+    ///
+    pub fn to_jwk_secret(&self) -> String {
+        let mut jwk = Jwk::from_public_key(&self.public_key());
+        jwk.d = Some(encode(&Zeroizing::new(self.to_bytes_raw())[..]));
+
+        jwk.to_json()
+    }
+
+    /// Signs `payload` as a compact JWS with `alg: EdDSA` (RFC 8037).
+    ///
+    /// ECDSA(secp256k1) keys return [`KeyError::AlgorithmMismatch`]: `ES256K` signs a
+    /// SHA-256 hash where they sign a Keccak-256 one.
+    ///
+    pub fn sign_jws(&self, payload: &[u8]) -> Result<String, KeyError> {
+        if !self.is_ed25519() {
+            return Err(KeyError::AlgorithmMismatch {
+                expected: KeyAlgorithm::Ed25519,
+                actual: self.algorithm(),
+            });
+        }
+
+        let signing_input = format!("{}.{}", encode(JWS_HEADER.as_bytes()), encode(payload));
+        let signature = self.sign(signing_input.as_bytes());
+
+        Ok(format!("{}.{}", signing_input, encode(signature.as_ref())))
+    }
+}
+
+fn encode(data: &[u8]) -> String {
+    Base64UrlUnpadded::encode_string(data)
+}
+
+fn decode(text: &str) -> Result<Vec<u8>, KeyError> {
+    Base64UrlUnpadded::decode_vec(text).map_err(|_| KeyError::Jose("invalid base64url"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::key_error::KeyError;
+    use crate::private_key::PrivateKey;
+    use crate::public_key::PublicKey;
+
+    // the examples of RFC 8037, appendix A
+    const ED25519_JWK: &str = r#"{"kty":"OKP","crv":"Ed25519","d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#;
+    const ED25519_PUBLIC_JWK: &str =
+        r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#;
+    const JWS_PAYLOAD: &[u8] = b"Example of Ed25519 signing";
+    const JWS: &str = "eyJhbGciOiJFZERTQSJ9.RXhhbXBsZSBvZiBFZDI1NTE5IHNpZ25pbmc.hgyY0il_MGCjP0JzlnLWG1PPOt7-09PGcvMg3AIbQR6dWbhijcNR4ki4iylGjg5BhVsPt9g7sVvpAr_MuM0KAg";
+
+    const ECDSA_PRIVATE_KEY_STR: &str = "3030020100300706052b8104000a042204200000000000000000000000000000000000000000000000000000000000000001";
+
+    #[test]
+    fn test_ed25519_jwk() -> Result<(), KeyError> {
+        let private_key = PrivateKey::from_jwk(ED25519_JWK)?;
+        let public_key = PublicKey::from_jwk(ED25519_PUBLIC_JWK)?;
+
+        assert_eq!(
+            hex::encode(private_key.to_bytes_raw()),
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60"
+        );
+        assert_eq!(private_key.public_key(), public_key);
+        assert_eq!(PublicKey::from_jwk(ED25519_JWK)?, public_key);
+
+        assert_eq!(public_key.to_jwk(), ED25519_PUBLIC_JWK);
+        assert_eq!(PrivateKey::from_jwk(&private_key.to_jwk_secret())?, private_key);
+
+        assert!(matches!(PrivateKey::from_jwk(ED25519_PUBLIC_JWK), Err(KeyError::Jose(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ecdsa_jwk() -> Result<(), KeyError> {
+        let private_key: PrivateKey = ECDSA_PRIVATE_KEY_STR.parse()?;
+        let jwk: serde_json::Value = serde_json::from_str(&private_key.public_key().to_jwk())?;
+
+        assert_eq!(jwk["kty"], "EC");
+        assert_eq!(jwk["crv"], "secp256k1");
+        assert_eq!(jwk["x"], "eb5mfvncu6xVoGKVzocLBwKb_NstzijZWfKBWxb4F5g");
+        assert_eq!(jwk["y"], "SDradyajxGVdpPv8DhEIqP0XtEimhVQZnEfQj_sQ1Lg");
+        assert!(jwk.get("d").is_none());
+
+        assert_eq!(PublicKey::from_jwk(&jwk.to_string())?, private_key.public_key());
+        assert_eq!(PrivateKey::from_jwk(&private_key.to_jwk_secret())?, private_key);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jwk_invalid() -> Result<(), KeyError> {
+        let mismatched = ED25519_JWK.replace("11qYAYKx", "21qYAYKx");
+        assert!(PrivateKey::from_jwk(&mismatched).is_err());
+
+        let other = PrivateKey::generate().public_key().to_jwk();
+        let mismatched =
+            format!(r#"{{"d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",{}"#, &other[1..]);
+        assert!(matches!(PrivateKey::from_jwk(&mismatched), Err(KeyError::PublicKeyMismatch)));
+
+        assert!(matches!(
+            PublicKey::from_jwk(r#"{"kty":"EC","crv":"P-256","x":"AA","y":"AA"}"#),
+            Err(KeyError::UnsupportedAlgorithm(algorithm)) if algorithm == "EC P-256"
+        ));
+        assert!(matches!(PublicKey::from_jwk(r#"{"kty":"OKP"}"#), Err(KeyError::Json(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jws() -> Result<(), KeyError> {
+        let private_key = PrivateKey::from_jwk(ED25519_JWK)?;
+        let public_key = private_key.public_key();
+
+        assert_eq!(private_key.sign_jws(JWS_PAYLOAD)?, JWS);
+        assert_eq!(public_key.verify_jws(JWS)?, JWS_PAYLOAD);
+
+        assert!(matches!(
+            PrivateKey::generate().public_key().verify_jws(JWS),
+            Err(KeyError::Jose("signature does not match"))
+        ));
+        assert!(matches!(
+            PrivateKey::generate_ecdsa().sign_jws(JWS_PAYLOAD),
+            Err(KeyError::AlgorithmMismatch { .. })
+        ));
+
+        // `{"alg":"none"}`
+        let unsigned = format!("eyJhbGciOiJub25lIn0.{}.", JWS.split('.').nth(1).unwrap());
+        assert!(matches!(public_key.verify_jws(&unsigned), Err(KeyError::Jose(_))));
+
+        // `{"alg":"EdDSA","crit":["exp"],"exp":1}`
+        let critical =
+            format!("eyJhbGciOiJFZERTQSIsImNyaXQiOlsiZXhwIl0sImV4cCI6MX0.{}", &JWS[21..]);
+        assert!(matches!(public_key.verify_jws(&critical), Err(KeyError::Jose(_))));
+
+        assert!(matches!(public_key.verify_jws(&JWS[21..]), Err(KeyError::Jose(_))));
+
+        Ok(())
+    }
+}
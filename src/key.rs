@@ -1,20 +1,145 @@
+#[cfg(feature = "serde")]
+use std::borrow::Cow;
+use std::convert::TryFrom;
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
+use prost::Message;
+
+use crate::contract_id::ContractId;
+use crate::key_error::KeyError;
 use crate::key_list::KeyList;
+use crate::key_list_error::KeyListError;
+use crate::proto;
 use crate::public_key::PublicKey;
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Key {
     PublicKey(PublicKey),
     KeyList(KeyList),
+
+    /// Satisfied when the contract itself is the caller.
+    ContractId(ContractId),
+
+    /// Satisfied when the contract is the caller, including through a `delegatecall`.
+    DelegatableContractId(ContractId),
+}
+
+impl Key {
+    /// Checks that this key would be accepted by the Hedera™ Network.
+    ///
+    /// See [`KeyList::validate`] for the rules key lists must follow.
+    ///
+    pub fn validate(&self) -> Result<(), KeyListError> {
+        match self {
+            Key::KeyList(list) => list.validate(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns how deeply keys are nested in this key, counting
+    /// every key list and the keys at the bottom as one level each.
+    pub fn depth(&self) -> usize {
+        match self {
+            Key::KeyList(list) => list.depth(),
+            _ => 1,
+        }
+    }
+
+    /// Returns the HAPI `Key` protobuf encoding of this key.
+    ///
+    /// A key list with a threshold is encoded as a `ThresholdKey`. Fails if this key
+    /// is not valid (see [`Key::validate`]).
+    ///
+    pub fn to_protobuf_bytes(&self) -> Result<Vec<u8>, KeyError> {
+        self.validate()?;
+
+        Ok(self.to_protobuf()?.encode_to_vec())
+    }
+
+    /// Decodes a key from its HAPI `Key` protobuf encoding.
+    pub fn from_protobuf_bytes(data: &[u8]) -> Result<Self, KeyError> {
+        Self::from_protobuf(proto::Key::decode(data)?)
+    }
+
+    pub(crate) fn to_protobuf(&self) -> Result<proto::Key, KeyListError> {
+        let key = match self {
+            Key::PublicKey(key) if key.is_ed25519() => proto::key::Key::Ed25519(key.to_bytes_raw()),
+
+            Key::PublicKey(key) => proto::key::Key::EcdsaSecp256k1(key.to_bytes_raw()),
+
+            Key::KeyList(list) => {
+                let keys = proto::KeyList {
+                    keys: list.iter().map(Key::to_protobuf).collect::<Result<_, _>>()?,
+                };
+
+                match list.threshold {
+                    Some(threshold) => {
+                        let threshold = u32::try_from(threshold).map_err(|_| {
+                            KeyListError::ThresholdOutOfRange { threshold, len: list.len() }
+                        })?;
+
+                        proto::key::Key::ThresholdKey(proto::ThresholdKey {
+                            threshold,
+                            keys: Some(keys),
+                        })
+                    }
+
+                    None => proto::key::Key::KeyList(keys),
+                }
+            }
+
+            Key::ContractId(id) => proto::key::Key::ContractId(id.to_protobuf()),
+
+            Key::DelegatableContractId(id) => {
+                proto::key::Key::DelegatableContractId(id.to_protobuf())
+            }
+        };
+
+        Ok(proto::Key { key: Some(key) })
+    }
+
+    pub(crate) fn from_protobuf(key: proto::Key) -> Result<Self, KeyError> {
+        let key = key.key.ok_or(KeyError::UnsupportedKeyType("empty key"))?;
+
+        match key {
+            proto::key::Key::Ed25519(bytes) => Ok(PublicKey::from_bytes_ed25519(&bytes)?.into()),
+
+            proto::key::Key::EcdsaSecp256k1(bytes) => {
+                Ok(PublicKey::from_bytes_ecdsa(&bytes)?.into())
+            }
+
+            proto::key::Key::KeyList(list) => Ok(KeyList::try_from(list)?.into()),
+
+            proto::key::Key::ThresholdKey(key) => {
+                let mut list = KeyList::try_from(key.keys.unwrap_or_default())?;
+                list.threshold = Some(key.threshold as usize);
+
+                Ok(list.into())
+            }
+
+            proto::key::Key::ContractId(id) => Ok(Key::ContractId(ContractId::from_protobuf(id)?)),
+
+            proto::key::Key::DelegatableContractId(id) => {
+                Ok(Key::DelegatableContractId(ContractId::from_protobuf(id)?))
+            }
+
+            proto::key::Key::Rsa3072(_) => Err(KeyError::UnsupportedKeyType("RSA-3072")),
+
+            proto::key::Key::Ecdsa384(_) => Err(KeyError::UnsupportedKeyType("ECDSA(P-384)")),
+        }
+    }
 }
 
+/// Writes the key as a key expression (see [`KeyList`]'s `Display`),
+/// which [`Key::from_str`](std::str::FromStr) can read back.
 impl Display for Key {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Key::PublicKey(key) => key.fmt(f),
             Key::KeyList(key) => key.fmt(f),
+            Key::ContractId(id) => write!(f, "contract({})", id),
+            Key::DelegatableContractId(id) => write!(f, "delegatable_contract({})", id),
         }
     }
 }
@@ -30,3 +155,222 @@ impl From<KeyList> for Key {
         Key::KeyList(list)
     }
 }
+
+impl From<ContractId> for Key {
+    fn from(id: ContractId) -> Self {
+        Key::ContractId(id)
+    }
+}
+
+/// The serialized form of a [`Key`], tagged with its `type`.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum KeyRepr<'a> {
+    PublicKey { key: Cow<'a, PublicKey> },
+    KeyList(Cow<'a, KeyList>),
+    ContractId { id: ContractId },
+    DelegatableContractId { id: ContractId },
+}
+
+/// Serializes as a structure tagged with the kind of key, such as
+/// `{"type":"keyList","keys":[{"type":"publicKey","key":"302a…"}],"threshold":1}`.
+///
+#[cfg(feature = "serde")]
+impl serde::Serialize for Key {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let repr = match self {
+            Key::PublicKey(key) => KeyRepr::PublicKey { key: Cow::Borrowed(key) },
+            Key::KeyList(list) => KeyRepr::KeyList(Cow::Borrowed(list)),
+            Key::ContractId(id) => KeyRepr::ContractId { id: *id },
+            Key::DelegatableContractId(id) => KeyRepr::DelegatableContractId { id: *id },
+        };
+
+        serde::Serialize::serialize(&repr, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Key {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match <KeyRepr as serde::Deserialize>::deserialize(deserializer)? {
+            KeyRepr::PublicKey { key } => Key::PublicKey(key.into_owned()),
+            KeyRepr::KeyList(list) => Key::KeyList(list.into_owned()),
+            KeyRepr::ContractId { id } => Key::ContractId(id),
+            KeyRepr::DelegatableContractId { id } => Key::DelegatableContractId(id),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::Key;
+    use crate::{ContractId, KeyError, KeyList, KeyListError, PublicKey};
+
+    const ED25519_PUBLIC_KEY: &str =
+        "302a300506032b6570032100d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    const ECDSA_PUBLIC_KEY: &str = "302d300706052b8104000a0322000279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    #[test]
+    fn test_public_key_to_protobuf() -> Result<(), KeyError> {
+        let ed25519_key: Key = PublicKey::from_str(ED25519_PUBLIC_KEY)?.into();
+        let ecdsa_key: Key = PublicKey::from_str(ECDSA_PUBLIC_KEY)?.into();
+
+        assert_eq!(
+            hex::encode(ed25519_key.to_protobuf_bytes()?),
+            "1220d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+        assert_eq!(
+            hex::encode(ecdsa_key.to_protobuf_bytes()?),
+            "3a210279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_threshold_key_to_protobuf() -> Result<(), KeyError> {
+        let mut list: KeyList =
+            vec![PublicKey::from_str(ED25519_PUBLIC_KEY)?].into_iter().collect();
+        list.threshold = Some(1);
+
+        let bytes = Key::from(list).to_protobuf_bytes()?;
+
+        assert_eq!(
+            hex::encode(&bytes),
+            "2a28080112240a221220d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+
+        match Key::from_protobuf_bytes(&bytes)? {
+            Key::KeyList(list) => {
+                assert_eq!(list.threshold, Some(1));
+                assert_eq!(list.len(), 1);
+            }
+
+            key => panic!("expected a key list, found {}", key),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_key_list_to_protobuf() -> Result<(), KeyError> {
+        let mut list: KeyList =
+            vec![PublicKey::from_str(ED25519_PUBLIC_KEY)?].into_iter().collect();
+
+        for &threshold in &[0, 2, usize::MAX] {
+            list.threshold = Some(threshold);
+
+            assert!(matches!(
+                Key::from(list.clone()).to_protobuf_bytes(),
+                Err(KeyError::KeyList(KeyListError::ThresholdOutOfRange { .. }))
+            ));
+        }
+
+        assert!(matches!(
+            Key::from(KeyList::new()).to_protobuf_bytes(),
+            Err(KeyError::KeyList(KeyListError::Empty))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_key_list_round_trip() -> Result<(), KeyError> {
+        let ed25519_key = PublicKey::from_str(ED25519_PUBLIC_KEY)?;
+        let ecdsa_key = PublicKey::from_str(ECDSA_PUBLIC_KEY)?;
+
+        let mut inner: KeyList = vec![ed25519_key.clone(), ecdsa_key.clone()].into_iter().collect();
+        inner.threshold = Some(1);
+
+        let outer: KeyList = vec![Key::from(ecdsa_key), Key::from(inner)].into_iter().collect();
+        let bytes = Key::from(outer.clone()).to_protobuf_bytes()?;
+
+        let key = Key::from_protobuf_bytes(&bytes)?;
+
+        assert_eq!(key.to_protobuf_bytes()?, bytes);
+        assert_eq!(key, Key::from(outer));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_contract_id_protobuf() -> Result<(), KeyError> {
+        let contract_key = Key::ContractId(ContractId::new(0, 0, 1234));
+        let delegatable_key = Key::DelegatableContractId(ContractId::new(0, 0, 1234));
+
+        assert_eq!(hex::encode(contract_key.to_protobuf_bytes()?), "0a0318d209");
+        assert_eq!(hex::encode(delegatable_key.to_protobuf_bytes()?), "420318d209");
+
+        assert_eq!(Key::from_protobuf_bytes(&contract_key.to_protobuf_bytes()?)?, contract_key);
+        assert_eq!(
+            Key::from_protobuf_bytes(&delegatable_key.to_protobuf_bytes()?)?,
+            delegatable_key
+        );
+        assert_ne!(contract_key, delegatable_key);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_contract_id_display() {
+        let id = ContractId::new(0, 0, 1234);
+
+        assert_eq!(Key::ContractId(id).to_string(), "contract(0.0.1234)");
+        assert_eq!(Key::DelegatableContractId(id).to_string(), "delegatable_contract(0.0.1234)");
+    }
+
+    #[test]
+    fn test_unsupported_protobuf() {
+        // an empty `Key` message
+        assert!(matches!(Key::from_protobuf_bytes(&[]), Err(KeyError::UnsupportedKeyType(_))));
+
+        // an `RSA_3072` key
+        assert!(matches!(
+            Key::from_protobuf_bytes(&[0x1a, 0x01, 0x00]),
+            Err(KeyError::UnsupportedKeyType(_))
+        ));
+
+        // truncated
+        assert!(matches!(Key::from_protobuf_bytes(&[0x12, 0x20]), Err(KeyError::Protobuf(_))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() -> Result<(), KeyError> {
+        let ed25519_key = Key::from(PublicKey::from_str(ED25519_PUBLIC_KEY)?);
+        let ecdsa_key = Key::from(PublicKey::from_str(ECDSA_PUBLIC_KEY)?);
+
+        let inner = KeyList {
+            keys: vec![ecdsa_key, Key::ContractId(ContractId::new(0, 0, 7))],
+            threshold: Some(1),
+        };
+        let key = Key::from(KeyList { keys: vec![ed25519_key, inner.into()], threshold: None });
+
+        let json = serde_json::to_value(&key).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "keyList",
+                "keys": [
+                    { "type": "publicKey", "key": ED25519_PUBLIC_KEY },
+                    {
+                        "type": "keyList",
+                        "keys": [
+                            { "type": "publicKey", "key": ECDSA_PUBLIC_KEY },
+                            { "type": "contractId", "id": "0.0.7" },
+                        ],
+                        "threshold": 1,
+                    },
+                ],
+            })
+        );
+
+        assert_eq!(serde_json::from_value::<Key>(json).unwrap(), key);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// The signature algorithm of a key on the Hedera™ Network
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum KeyAlgorithm {
+    /// Ed25519 (EdDSA over Curve25519)
+    Ed25519,
+
+    /// ECDSA over the secp256k1 curve, signing the Keccak-256 hash of the message
+    EcdsaSecp256k1,
+}
+
+impl Display for KeyAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyAlgorithm::Ed25519 => f.write_str("Ed25519"),
+            KeyAlgorithm::EcdsaSecp256k1 => f.write_str("ECDSA(secp256k1)"),
+        }
+    }
+}
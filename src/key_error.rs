@@ -1,6 +1,8 @@
 use ed25519_dalek::SignatureError;
 use thiserror::Error;
 
+use crate::key_algorithm::KeyAlgorithm;
+use crate::key_list_error::KeyListError;
 use crate::keystore_error::KeystoreError;
 use crate::mnemonic_error::MnemonicError;
 
@@ -15,12 +17,75 @@ pub enum KeyError {
     #[error("invalid private key length: {0} bytes")]
     Length(usize),
 
+    #[error("expected an {expected} key but found an {actual} key")]
+    AlgorithmMismatch { expected: KeyAlgorithm, actual: KeyAlgorithm },
+
     #[error(transparent)]
     Signature(#[from] SignatureError),
 
+    #[error("invalid signature: {0}")]
+    InvalidSignature(&'static str),
+
+    #[error(transparent)]
+    Ecdsa(#[from] k256::elliptic_curve::Error),
+
+    #[error("invalid EVM address length: {0} bytes")]
+    EvmAddressLength(usize),
+
+    #[error("EVM address does not match its EIP-55 checksum")]
+    EvmAddressChecksum,
+
+    #[error("invalid Ed25519 public key: {0}")]
+    Ed25519PublicKey(&'static str),
+
+    #[error("invalid DER: {0}")]
+    Der(&'static str),
+
+    #[error("unsupported key algorithm or curve: {0}")]
+    UnsupportedAlgorithm(String),
+
+    #[error("the embedded public key does not match the private key")]
+    PublicKeyMismatch,
+
+    #[error("invalid PKCS#8 document: {0}")]
+    Pkcs8(pkcs8::Error),
+
+    #[error("unexpected PEM label: {0:?}")]
+    PemLabel(String),
+
+    #[error("invalid OpenSSH data: {0}")]
+    Ssh(&'static str),
+
+    #[error("invalid COSE object: {0}")]
+    Cose(&'static str),
+
+    #[error("invalid JWK or JWS: {0}")]
+    Jose(&'static str),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("invalid contract ID: {0:?}")]
+    ContractId(String),
+
+    #[error("invalid key expression at position {position}: {reason}")]
+    KeyExpression { position: usize, reason: String },
+
+    #[error(transparent)]
+    Protobuf(#[from] prost::DecodeError),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error("unsupported key type: {0}")]
+    UnsupportedKeyType(&'static str),
+
     #[error("legacy 22-word mnemonics do not support passphrases")]
     PassphraseUnsupported,
 
+    #[error(transparent)]
+    KeyList(#[from] KeyListError),
+
     #[error(transparent)]
     Mnemonic(#[from] MnemonicError),
 
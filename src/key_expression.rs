@@ -0,0 +1,321 @@
+//! A human-readable syntax for keys, used by the `Display` and `FromStr`
+//! implementations of [`Key`] and [`KeyList`]:
+//!
+//! ```text
+//! key      = list | contract | public-key
+//! list     = ( threshold | "all" ) "of" "[" [ key { "," key } ] "]"
+//! contract = ( "contract" | "delegatable_contract" ) "(" contract-id ")"
+//! ```
+//!
+//! Public keys are written as hex-encoded DER and contract IDs as `shard.realm.num`.
+//! For example, `2 of [302a…, 302a…, 1 of [302a…, 302a…]]`.
+
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use crate::contract_id::ContractId;
+use crate::key::Key;
+use crate::key_error::KeyError;
+use crate::key_list::KeyList;
+use crate::public_key::PublicKey;
+
+const INDENT: &str = "    ";
+
+// well past `KeyList::MAX_DEPTH`, but keeps hostile input from overflowing the stack
+const MAX_NESTING: usize = 100;
+
+/// Writes `list` on one line, or across indented lines in alternate (`{:#}`) mode.
+pub(crate) fn fmt_key_list(list: &KeyList, f: &mut Formatter<'_>, depth: usize) -> fmt::Result {
+    match list.threshold {
+        Some(threshold) => write!(f, "{} of [", threshold)?,
+        None => write!(f, "all of [")?,
+    }
+
+    for (i, key) in list.iter().enumerate() {
+        if i > 0 {
+            write!(f, ",")?;
+        }
+
+        if f.alternate() {
+            write!(f, "\n{}", INDENT.repeat(depth + 1))?;
+        } else if i > 0 {
+            write!(f, " ")?;
+        }
+
+        match key {
+            Key::KeyList(list) => fmt_key_list(list, f, depth + 1)?,
+            key => write!(f, "{}", key)?,
+        }
+    }
+
+    if f.alternate() && !list.is_empty() {
+        write!(f, "\n{}", INDENT.repeat(depth))?;
+    }
+
+    write!(f, "]")
+}
+
+impl FromStr for Key {
+    type Err = KeyError;
+
+    fn from_str(text: &str) -> Result<Self, KeyError> {
+        let mut parser = Parser { text, position: 0, nesting: 0 };
+
+        let key = parser.key()?;
+        parser.skip_whitespace();
+
+        if parser.position < text.len() {
+            return Err(parser.error("expected the end of the key"));
+        }
+
+        Ok(key)
+    }
+}
+
+impl FromStr for KeyList {
+    type Err = KeyError;
+
+    fn from_str(text: &str) -> Result<Self, KeyError> {
+        match Key::from_str(text)? {
+            Key::KeyList(list) => Ok(list),
+            _ => Err(KeyError::KeyExpression { position: 0, reason: "expected a key list".into() }),
+        }
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+    nesting: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, reason: impl Into<String>) -> KeyError {
+        KeyError::KeyExpression { position: self.position, reason: reason.into() }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes `token` if it comes next.
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), KeyError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{}`", token)))
+        }
+    }
+
+    /// Consumes a run of characters matching `predicate`.
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        self.skip_whitespace();
+
+        let start = self.position;
+        let len = self.rest().find(|c| !predicate(c)).unwrap_or_else(|| self.rest().len());
+        self.position += len;
+
+        &self.text[start..self.position]
+    }
+
+    fn key(&mut self) -> Result<Key, KeyError> {
+        let start = self.position;
+        let word = self.take_while(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        match word {
+            "" => Err(self.error("expected a key")),
+
+            "all" => self.key_list(None),
+
+            "contract" => Ok(Key::ContractId(self.contract_id()?)),
+
+            "delegatable_contract" => Ok(Key::DelegatableContractId(self.contract_id()?)),
+
+            word if word.bytes().all(|b| b.is_ascii_digit()) && self.eat("of") => {
+                let threshold = word.parse().map_err(|_| KeyError::KeyExpression {
+                    position: start,
+                    reason: "invalid threshold".into(),
+                })?;
+
+                self.key_list(Some(threshold))
+            }
+
+            word => {
+                let key = PublicKey::from_str(word).map_err(|error| KeyError::KeyExpression {
+                    position: start,
+                    reason: format!("invalid public key: {}", error),
+                })?;
+
+                Ok(Key::PublicKey(key))
+            }
+        }
+    }
+
+    /// Parses the rest of a key list, after its threshold.
+    fn key_list(&mut self, threshold: Option<usize>) -> Result<Key, KeyError> {
+        if threshold.is_none() {
+            self.expect("of")?;
+        }
+
+        self.expect("[")?;
+
+        if self.nesting == MAX_NESTING {
+            return Err(self.error("key lists are nested too deeply"));
+        }
+
+        self.nesting += 1;
+
+        let mut keys = Vec::new();
+
+        if !self.eat("]") {
+            loop {
+                keys.push(self.key()?);
+
+                if self.eat("]") {
+                    break;
+                }
+
+                self.expect(",")?;
+            }
+        }
+
+        self.nesting -= 1;
+
+        Ok(Key::KeyList(KeyList { keys, threshold }))
+    }
+
+    fn contract_id(&mut self) -> Result<ContractId, KeyError> {
+        self.expect("(")?;
+
+        let start = self.position;
+        let text = self.take_while(|c| c != ')').trim_end();
+
+        let id = ContractId::from_str(text).map_err(|error| KeyError::KeyExpression {
+            position: start,
+            reason: error.to_string(),
+        })?;
+
+        self.expect(")")?;
+
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{ContractId, Key, KeyError, KeyList, PublicKey};
+
+    const ED25519_PUBLIC_KEY: &str =
+        "302a300506032b6570032100d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    const ECDSA_PUBLIC_KEY: &str = "302d300706052b8104000a0322000279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    fn gen_key() -> Result<Key, KeyError> {
+        let ed25519_key = Key::from(PublicKey::from_str(ED25519_PUBLIC_KEY)?);
+        let ecdsa_key = Key::from(PublicKey::from_str(ECDSA_PUBLIC_KEY)?);
+
+        let inner = KeyList {
+            keys: vec![ecdsa_key.clone(), Key::DelegatableContractId(ContractId::new(0, 0, 7))],
+            threshold: Some(1),
+        };
+
+        let outer = KeyList {
+            keys: vec![ed25519_key, ecdsa_key, inner.into(), KeyList::new().into()],
+            threshold: Some(2),
+        };
+
+        Ok(outer.into())
+    }
+
+    #[test]
+    fn test_display() -> Result<(), KeyError> {
+        assert_eq!(
+            gen_key()?.to_string(),
+            format!(
+                "2 of [{}, {}, 1 of [{}, delegatable_contract(0.0.7)], all of []]",
+                ED25519_PUBLIC_KEY, ECDSA_PUBLIC_KEY, ECDSA_PUBLIC_KEY
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pretty_display() -> Result<(), KeyError> {
+        let expected = format!(
+            "2 of [\n    {},\n    {},\n    1 of [\n        {},\n        delegatable_contract(0.0.7)\n    ],\n    all of []\n]",
+            ED25519_PUBLIC_KEY, ECDSA_PUBLIC_KEY, ECDSA_PUBLIC_KEY
+        );
+
+        assert_eq!(format!("{:#}", gen_key()?), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<(), KeyError> {
+        let key = gen_key()?;
+
+        assert_eq!(Key::from_str(&key.to_string())?, key);
+        assert_eq!(Key::from_str(&format!("{:#}", key))?, key);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse() -> Result<(), KeyError> {
+        let text = format!("all of[ {} ,contract( 1.2.3 ) ]", ED25519_PUBLIC_KEY);
+        let list = KeyList::from_str(&text)?;
+
+        assert_eq!(list.threshold, None);
+        assert_eq!(list[1], Key::ContractId(ContractId::new(1, 2, 3)));
+
+        assert_eq!(Key::from_str(ECDSA_PUBLIC_KEY)?, PublicKey::from_str(ECDSA_PUBLIC_KEY)?.into());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let deep = "1 of [".repeat(1000);
+
+        let cases = [
+            ("", 0),
+            ("2 of [", 6),
+            ("2 of []]", 7),
+            ("all [", 4),
+            ("1 of [302a]", 6),
+            ("contract(0.0)", 9),
+            ("1 of [contract(0.0.1) contract(0.0.2)]", 22),
+            (&deep, 606),
+        ];
+
+        for (text, expected) in &cases {
+            match Key::from_str(text) {
+                Err(KeyError::KeyExpression { position, .. }) => {
+                    assert_eq!(position, *expected, "{}", text)
+                }
+
+                result => panic!("expected an error for {:?}, found {:?}", text, result),
+            }
+        }
+    }
+}
@@ -1,14 +1,20 @@
+use std::convert::TryFrom;
 use std::fmt;
 use std::iter::FromIterator;
 use std::ops::{Deref, DerefMut};
 
-use itertools::Itertools;
-
 use crate::key::Key;
+use crate::key_error::KeyError;
+use crate::key_expression::fmt_key_list;
+use crate::key_list_error::KeyListError;
+use crate::proto;
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KeyList {
     pub keys: Vec<Key>,
+
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
     pub threshold: Option<usize>,
 }
 
@@ -18,12 +24,24 @@ impl From<Vec<Key>> for KeyList {
     }
 }
 
+impl TryFrom<proto::KeyList> for KeyList {
+    type Error = KeyError;
+
+    fn try_from(list: proto::KeyList) -> Result<Self, KeyError> {
+        let keys = list.keys.into_iter().map(Key::from_protobuf).collect::<Result<_, _>>()?;
+
+        Ok(KeyList { keys, threshold: None })
+    }
+}
+
+/// Writes the list as a key expression, such as `2 of [302a…, 302a…, 1 of [302a…, 302a…]]`,
+/// which [`KeyList::from_str`](std::str::FromStr) can read back.
+///
+/// The alternate form (`{:#}`) writes every key on its own, indented line.
+///
 impl fmt::Display for KeyList {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("KeyList")
-            .field("threshold", &self.threshold)
-            .field("keys", &format!("[{}]", self.keys.iter().format(",")))
-            .finish()
+        fmt_key_list(self, f, 0)
     }
 }
 
@@ -54,15 +72,71 @@ impl<K: Into<Key>> FromIterator<K> for KeyList {
 }
 
 impl KeyList {
+    /// The deepest a key can be nested and still be accepted by the Hedera™ Network,
+    /// counting the outermost list and the keys at the bottom as one level each.
+    pub const MAX_DEPTH: usize = 15;
+
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Returns a key list requiring `threshold` of `keys` to sign,
+    /// after checking it with [`KeyList::validate`].
+    pub fn with_threshold(keys: Vec<Key>, threshold: usize) -> Result<Self, KeyListError> {
+        let list = KeyList { keys, threshold: Some(threshold) };
+        list.validate()?;
+
+        Ok(list)
+    }
+
+    /// Checks that this key list would be accepted by the Hedera™ Network.
+    ///
+    /// This list and every list nested in it must be non-empty and have a threshold
+    /// (if any) of at least one and at most the number of its keys, and the whole list
+    /// must be no deeper than [`KeyList::MAX_DEPTH`].
+    ///
+    pub fn validate(&self) -> Result<(), KeyListError> {
+        let depth = self.depth();
+
+        if depth > Self::MAX_DEPTH {
+            return Err(KeyListError::TooDeep { depth, max: Self::MAX_DEPTH });
+        }
+
+        self.validate_lists()
+    }
+
+    fn validate_lists(&self) -> Result<(), KeyListError> {
+        if self.keys.is_empty() {
+            return Err(KeyListError::Empty);
+        }
+
+        if let Some(threshold) = self.threshold {
+            if threshold == 0 || threshold > self.keys.len() {
+                return Err(KeyListError::ThresholdOutOfRange { threshold, len: self.keys.len() });
+            }
+        }
+
+        for key in &self.keys {
+            if let Key::KeyList(list) = key {
+                list.validate_lists()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns how deeply keys are nested in this list, counting this list
+    /// and the keys at the bottom as one level each.
+    pub fn depth(&self) -> usize {
+        1 + self.keys.iter().map(Key::depth).max().unwrap_or(0)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::KeyList;
     use crate::key::Key;
+    use crate::key_list_error::KeyListError;
     use crate::public_key::PublicKey;
 
     const PUBLIC_KEY_BYTES: &[u8] = &[
@@ -98,6 +172,49 @@ mod tests {
     #[test]
     fn test_display() {}
 
+    #[test]
+    fn test_with_threshold() -> Result<(), KeyListError> {
+        let key_list = KeyList::with_threshold(gen_key_vec(), 2)?;
+        assert_eq!(key_list.threshold, Some(2));
+
+        assert_eq!(
+            KeyList::with_threshold(gen_key_vec(), 3),
+            Err(KeyListError::ThresholdOutOfRange { threshold: 3, len: 2 })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(KeyList::new().validate(), Err(KeyListError::Empty));
+        assert_eq!(KeyList::from(gen_key_vec()).validate(), Ok(()));
+
+        let key_list = KeyList { keys: gen_key_vec(), threshold: Some(0) };
+        assert_eq!(
+            key_list.validate(),
+            Err(KeyListError::ThresholdOutOfRange { threshold: 0, len: 2 })
+        );
+    }
+
+    #[test]
+    fn test_validate_depth() {
+        let mut key_list = KeyList::from(gen_key_vec());
+        assert_eq!(key_list.depth(), 2);
+
+        while key_list.depth() < KeyList::MAX_DEPTH {
+            key_list = KeyList::from(vec![Key::from(key_list)]);
+        }
+
+        assert_eq!(key_list.validate(), Ok(()));
+
+        let key_list = KeyList::from(vec![Key::from(key_list)]);
+        assert_eq!(
+            key_list.validate(),
+            Err(KeyListError::TooDeep { depth: KeyList::MAX_DEPTH + 1, max: KeyList::MAX_DEPTH })
+        );
+    }
+
     fn gen_key_vec() -> Vec<Key> {
         let public_key_1 = PublicKey::from_bytes(PUBLIC_KEY_BYTES).unwrap();
         let public_key_2 = PublicKey::from_bytes(PUBLIC_KEY_BYTES).unwrap();
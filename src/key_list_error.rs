@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum KeyListError {
+    #[error("key lists must contain at least one key")]
+    Empty,
+
+    #[error("a threshold of {threshold} is out of range for a key list of {len} keys")]
+    ThresholdOutOfRange { threshold: usize, len: usize },
+
+    #[error("the key list has no threshold")]
+    MissingThreshold,
+
+    #[error("keys can be nested at most {max} levels deep, found {depth}")]
+    TooDeep { depth: usize, max: usize },
+}
@@ -0,0 +1,233 @@
+use crate::contract_id::ContractId;
+use crate::key::Key;
+use crate::public_key::PublicKey;
+
+/// How a key, and every key nested in it, is satisfied by a set of signatures.
+///
+/// Returned by [`Key::is_satisfied_by`].
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KeySatisfaction {
+    /// A public key, satisfied if one of the signatures was made by it.
+    PublicKey { key: PublicKey, satisfied: bool },
+
+    /// A key list, satisfied if at least `required` of its keys are satisfied.
+    ///
+    /// An empty key list is never satisfied, as the Hedera™ Network does not accept one.
+    ///
+    KeyList { required: usize, satisfied: bool, keys: Vec<KeySatisfaction> },
+
+    /// A contract key, which can only be satisfied by the contract itself and
+    /// never by signatures.
+    ContractId { id: ContractId, delegatable: bool },
+}
+
+impl KeySatisfaction {
+    /// Returns `true` if the signatures satisfy this key.
+    pub fn is_satisfied(&self) -> bool {
+        match self {
+            KeySatisfaction::PublicKey { satisfied, .. } => *satisfied,
+            KeySatisfaction::KeyList { satisfied, .. } => *satisfied,
+            KeySatisfaction::ContractId { .. } => false,
+        }
+    }
+
+    /// Returns every public key in this key that has a valid signature.
+    pub fn satisfied_keys(&self) -> Vec<&PublicKey> {
+        let mut keys = Vec::new();
+        self.collect_keys(true, &mut keys);
+        keys
+    }
+
+    /// Returns every public key in this key that has no valid signature.
+    pub fn unsatisfied_keys(&self) -> Vec<&PublicKey> {
+        let mut keys = Vec::new();
+        self.collect_keys(false, &mut keys);
+        keys
+    }
+
+    fn collect_keys<'a>(&'a self, want_satisfied: bool, keys: &mut Vec<&'a PublicKey>) {
+        match self {
+            KeySatisfaction::PublicKey { key, satisfied } if *satisfied == want_satisfied => {
+                keys.push(key);
+            }
+
+            KeySatisfaction::KeyList { keys: children, .. } => {
+                for child in children {
+                    child.collect_keys(want_satisfied, keys);
+                }
+            }
+
+            _ => {}
+        }
+    }
+}
+
+impl Key {
+    /// Checks which parts of this key are satisfied by `signatures` over `message`.
+    ///
+    /// Each signature is paired with the public key that claims to have made it, and only
+    /// counts towards that key. Key lists without a threshold need every one of their keys
+    /// to be satisfied, and key lists with a threshold of zero or above their number of keys
+    /// are never satisfied.
+    ///
+    pub fn is_satisfied_by<S: AsRef<[u8]>>(
+        &self,
+        message: &[u8],
+        signatures: &[(PublicKey, S)],
+    ) -> KeySatisfaction {
+        match self {
+            Key::PublicKey(key) => {
+                let satisfied = signatures.iter().any(|(signer, signature)| {
+                    signer == key && key.verify_raw(message, signature.as_ref())
+                });
+
+                KeySatisfaction::PublicKey { key: key.clone(), satisfied }
+            }
+
+            Key::KeyList(list) => {
+                let keys: Vec<_> =
+                    list.iter().map(|key| key.is_satisfied_by(message, signatures)).collect();
+
+                let required = list.threshold.unwrap_or_else(|| list.len());
+
+                // an empty list or a threshold out of range fails closed, as the network
+                // would not accept either
+                let satisfied = (1..=keys.len()).contains(&required)
+                    && keys.iter().filter(|key| key.is_satisfied()).count() >= required;
+
+                KeySatisfaction::KeyList { required, satisfied, keys }
+            }
+
+            Key::ContractId(id) => KeySatisfaction::ContractId { id: *id, delegatable: false },
+
+            Key::DelegatableContractId(id) => {
+                KeySatisfaction::ContractId { id: *id, delegatable: true }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeySatisfaction;
+    use crate::{ContractId, Key, KeyError, KeyList, PrivateKey};
+
+    const MESSAGE: &[u8] = b"hello, world";
+
+    #[test]
+    fn test_public_key() {
+        let private_key = PrivateKey::generate();
+        let key = Key::from(private_key.public_key());
+
+        let signatures = [(private_key.public_key(), private_key.sign(MESSAGE))];
+
+        assert!(key.is_satisfied_by(MESSAGE, &signatures).is_satisfied());
+        assert!(!key.is_satisfied_by(b"goodbye, world", &signatures).is_satisfied());
+        assert!(!key.is_satisfied_by::<Vec<u8>>(MESSAGE, &[]).is_satisfied());
+    }
+
+    #[test]
+    fn test_signature_from_another_key() {
+        let private_key = PrivateKey::generate();
+        let other_key = PrivateKey::generate();
+        let key = Key::from(private_key.public_key());
+
+        // a valid signature, but claimed by the wrong key
+        let signatures = [(other_key.public_key(), private_key.sign(MESSAGE))];
+
+        assert!(!key.is_satisfied_by(MESSAGE, &signatures).is_satisfied());
+    }
+
+    #[test]
+    fn test_nested_thresholds() {
+        let private_keys: Vec<_> = (0..4).map(|_| PrivateKey::generate()).collect();
+
+        // 2 of [a, b, 1 of [c, d]]
+        let mut inner: KeyList = private_keys[2..].iter().map(PrivateKey::public_key).collect();
+        inner.threshold = Some(1);
+
+        let mut outer: KeyList = private_keys[..2].iter().map(PrivateKey::public_key).collect();
+        outer.push(inner.into());
+        outer.threshold = Some(2);
+
+        let key = Key::from(outer);
+
+        let sign = |indices: &[usize]| -> Vec<_> {
+            indices
+                .iter()
+                .map(|&i| (private_keys[i].public_key(), private_keys[i].sign(MESSAGE)))
+                .collect()
+        };
+
+        assert!(key.is_satisfied_by(MESSAGE, &sign(&[0, 1])).is_satisfied());
+        assert!(key.is_satisfied_by(MESSAGE, &sign(&[0, 3])).is_satisfied());
+        assert!(!key.is_satisfied_by(MESSAGE, &sign(&[2, 3])).is_satisfied());
+
+        let satisfaction = key.is_satisfied_by(MESSAGE, &sign(&[1, 2]));
+        assert!(satisfaction.is_satisfied());
+        assert_eq!(
+            satisfaction.satisfied_keys(),
+            vec![&private_keys[1].public_key(), &private_keys[2].public_key()]
+        );
+        assert_eq!(
+            satisfaction.unsatisfied_keys(),
+            vec![&private_keys[0].public_key(), &private_keys[3].public_key()]
+        );
+
+        match satisfaction {
+            KeySatisfaction::KeyList { required, keys, .. } => {
+                assert_eq!(required, 2);
+                assert!(keys[2].is_satisfied());
+            }
+
+            _ => panic!("expected a key list"),
+        }
+    }
+
+    #[test]
+    fn test_key_list_without_threshold() {
+        let private_keys: Vec<_> = (0..2).map(|_| PrivateKey::generate()).collect();
+        let key = Key::from(private_keys.iter().map(PrivateKey::public_key).collect::<KeyList>());
+
+        let one = [(private_keys[0].public_key(), private_keys[0].sign(MESSAGE))];
+        assert!(!key.is_satisfied_by(MESSAGE, &one).is_satisfied());
+
+        let both: Vec<_> =
+            private_keys.iter().map(|key| (key.public_key(), key.sign(MESSAGE))).collect();
+        assert!(key.is_satisfied_by(MESSAGE, &both).is_satisfied());
+
+        assert!(!Key::from(KeyList::new()).is_satisfied_by(MESSAGE, &both).is_satisfied());
+    }
+
+    #[test]
+    fn test_threshold_out_of_range() -> Result<(), KeyError> {
+        let private_keys: Vec<_> = (0..2).map(|_| PrivateKey::generate()).collect();
+        let signatures: Vec<_> =
+            private_keys.iter().map(|key| (key.public_key(), key.sign(MESSAGE))).collect();
+
+        let mut list: KeyList = private_keys.iter().map(PrivateKey::public_key).collect();
+
+        for threshold in [0, 3] {
+            list.threshold = Some(threshold);
+
+            let key = Key::from(list.clone());
+            assert!(!key.is_satisfied_by(MESSAGE, &signatures).is_satisfied());
+            assert!(!key.is_satisfied_by::<&[u8]>(MESSAGE, &[]).is_satisfied());
+        }
+
+        let key: Key = format!("0 of [{}]", private_keys[0].public_key()).parse()?;
+        assert!(!key.is_satisfied_by::<&[u8]>(MESSAGE, &[]).is_satisfied());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_contract_id() {
+        let private_key = PrivateKey::generate();
+        let signatures = [(private_key.public_key(), private_key.sign(MESSAGE))];
+
+        let key = Key::ContractId(ContractId::new(0, 0, 1234));
+        assert!(!key.is_satisfied_by(MESSAGE, &signatures).is_satisfied());
+    }
+}
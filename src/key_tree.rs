@@ -0,0 +1,325 @@
+use std::slice;
+
+use crate::contract_id::ContractId;
+use crate::key::Key;
+use crate::key_list::KeyList;
+use crate::public_key::PublicKey;
+
+/// Visits every key in a key tree, in order.
+///
+/// Passed to [`Key::accept`]. Every method does nothing by default.
+///
+pub trait KeyVisitor {
+    fn visit_public_key(&mut self, _key: &PublicKey) {}
+
+    fn visit_contract_id(&mut self, _id: &ContractId, _delegatable: bool) {}
+
+    /// Called before the keys in `list` are visited.
+    fn enter_key_list(&mut self, _list: &KeyList) {}
+
+    /// Called after the keys in `list` are visited.
+    fn leave_key_list(&mut self, _list: &KeyList) {}
+}
+
+/// An iterator over the keys at the bottom of a key tree, in order.
+///
+/// Returned by [`Key::leaves`] and [`KeyList::leaves`].
+///
+#[derive(Clone, Debug)]
+pub struct Leaves<'a> {
+    stack: Vec<slice::Iter<'a, Key>>,
+}
+
+impl<'a> Iterator for Leaves<'a> {
+    type Item = &'a Key;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(keys) = self.stack.last_mut() {
+            match keys.next() {
+                Some(Key::KeyList(list)) => self.stack.push(list.iter()),
+                Some(key) => return Some(key),
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl Key {
+    /// Returns the public and contract keys in this key, skipping over key lists.
+    pub fn leaves(&self) -> Leaves<'_> {
+        Leaves { stack: vec![slice::from_ref(self).iter()] }
+    }
+
+    /// Returns `true` if `key` appears anywhere in this key.
+    pub fn contains(&self, key: &PublicKey) -> bool {
+        self.leaves().any(|leaf| matches!(leaf, Key::PublicKey(leaf) if leaf == key))
+    }
+
+    /// Returns `true` if this key and `other` are the same apart from the order of keys
+    /// in their key lists.
+    ///
+    /// A key list without a threshold is equivalent to one with a threshold of all its keys.
+    ///
+    pub fn is_equivalent(&self, other: &Key) -> bool {
+        match (self, other) {
+            (Key::KeyList(list), Key::KeyList(other)) => list.is_equivalent(other),
+            _ => self == other,
+        }
+    }
+
+    /// Removes repeated keys from the key lists in this key, wherever that does not
+    /// change which signatures satisfy the list.
+    ///
+    /// That is, from lists that need one or all of their keys. Other threshold lists are
+    /// left alone, as a repeated key there counts once for every time it appears.
+    ///
+    pub fn dedup(&mut self) {
+        if let Key::KeyList(list) = self {
+            list.dedup_keys();
+        }
+    }
+
+    /// Calls `visitor` for every key in this key, in order.
+    pub fn accept<V: KeyVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Key::PublicKey(key) => visitor.visit_public_key(key),
+
+            Key::KeyList(list) => {
+                visitor.enter_key_list(list);
+
+                for key in list.iter() {
+                    key.accept(visitor);
+                }
+
+                visitor.leave_key_list(list);
+            }
+
+            Key::ContractId(id) => visitor.visit_contract_id(id, false),
+            Key::DelegatableContractId(id) => visitor.visit_contract_id(id, true),
+        }
+    }
+}
+
+impl KeyList {
+    /// Returns the public and contract keys in this list, skipping over nested key lists.
+    pub fn leaves(&self) -> Leaves<'_> {
+        Leaves { stack: vec![self.iter()] }
+    }
+
+    /// Returns `true` if this list and `other` are the same apart from the order of keys
+    /// in them and in the lists nested in them.
+    ///
+    /// See [`Key::is_equivalent`].
+    ///
+    pub fn is_equivalent(&self, other: &KeyList) -> bool {
+        if self.len() != other.len() || self.required() != other.required() {
+            return false;
+        }
+
+        let mut matched = vec![false; other.len()];
+
+        self.iter().all(|key| {
+            let found = other
+                .iter()
+                .zip(&mut matched)
+                .find(|(other, matched)| !**matched && key.is_equivalent(other));
+
+            match found {
+                Some((_, matched)) => {
+                    *matched = true;
+                    true
+                }
+
+                None => false,
+            }
+        })
+    }
+
+    /// Returns the number of keys that must be satisfied to satisfy this list.
+    fn required(&self) -> usize {
+        self.threshold.unwrap_or_else(|| self.len())
+    }
+
+    fn dedup_keys(&mut self) {
+        for key in self.iter_mut() {
+            key.dedup();
+        }
+
+        let needs_all = self.required() == self.len();
+
+        if !needs_all && self.threshold != Some(1) {
+            return;
+        }
+
+        let mut keys: Vec<Key> = Vec::with_capacity(self.len());
+
+        for key in self.keys.drain(..) {
+            if !keys.iter().any(|other| other.is_equivalent(&key)) {
+                keys.push(key);
+            }
+        }
+
+        if needs_all && self.threshold.is_some() {
+            self.threshold = Some(keys.len());
+        }
+
+        self.keys = keys;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::str::FromStr;
+
+    use super::KeyVisitor;
+    use crate::{ContractId, Key, KeyError, KeyList, PrivateKey, PublicKey};
+
+    fn gen_keys() -> Vec<PublicKey> {
+        (0..4).map(|_| PrivateKey::generate().public_key()).collect()
+    }
+
+    fn list(threshold: Option<usize>, keys: Vec<Key>) -> Key {
+        Key::KeyList(KeyList { keys, threshold })
+    }
+
+    #[test]
+    fn test_leaves() {
+        let keys = gen_keys();
+        let contract = Key::ContractId(ContractId::new(0, 0, 5));
+
+        let key = list(
+            Some(1),
+            vec![
+                keys[0].clone().into(),
+                list(None, vec![keys[1].clone().into(), list(None, vec![]), contract.clone()]),
+                keys[2].clone().into(),
+            ],
+        );
+
+        let leaves: Vec<&Key> = key.leaves().collect();
+
+        assert_eq!(
+            leaves,
+            vec![
+                &Key::from(keys[0].clone()),
+                &Key::from(keys[1].clone()),
+                &contract,
+                &Key::from(keys[2].clone())
+            ]
+        );
+
+        assert!(key.contains(&keys[1]));
+        assert!(!key.contains(&keys[3]));
+
+        let leaf = Key::from(keys[3].clone());
+        assert_eq!(leaf.leaves().collect::<Vec<_>>(), vec![&leaf]);
+        assert!(leaf.contains(&keys[3]));
+    }
+
+    #[test]
+    fn test_is_equivalent() {
+        let keys = gen_keys();
+        let key = |i: usize| Key::from(keys[i].clone());
+
+        let a = list(Some(2), vec![key(0), key(1), list(None, vec![key(2), key(3)])]);
+        let b = list(Some(2), vec![list(Some(2), vec![key(3), key(2)]), key(1), key(0)]);
+
+        assert!(a.is_equivalent(&b));
+        assert_ne!(a, b);
+
+        // a different threshold
+        assert!(!a
+            .is_equivalent(&list(Some(1), vec![key(0), key(1), list(None, vec![key(2), key(3)])])));
+
+        // repeated keys must be matched one for one
+        assert!(!list(None, vec![key(0), key(0), key(1)])
+            .is_equivalent(&list(None, vec![key(0), key(1), key(1)])));
+    }
+
+    #[test]
+    fn test_hash() -> Result<(), KeyError> {
+        let keys = gen_keys();
+        let key = list(Some(1), vec![keys[0].clone().into(), keys[1].clone().into()]);
+
+        let mut set = HashSet::new();
+        set.insert(key.clone());
+        set.insert(Key::from_str(&key.to_string())?);
+
+        assert_eq!(set.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dedup() {
+        let keys = gen_keys();
+        let key = |i: usize| Key::from(keys[i].clone());
+
+        let mut all = list(
+            Some(3),
+            vec![
+                key(0),
+                list(None, vec![key(1), key(2)]),
+                list(None, vec![key(2), key(1), key(1)]),
+            ],
+        );
+        all.dedup();
+        assert_eq!(all, list(Some(2), vec![key(0), list(None, vec![key(1), key(2)])]));
+
+        let mut one = list(Some(1), vec![key(0), key(1), key(0)]);
+        one.dedup();
+        assert_eq!(one, list(Some(1), vec![key(0), key(1)]));
+
+        // [0, 1] satisfies this, but would not satisfy `3 of [0, 1, 2]`
+        let mut threshold = list(Some(3), vec![key(0), key(0), key(1), key(2)]);
+        let before = threshold.clone();
+        threshold.dedup();
+        assert_eq!(threshold, before);
+    }
+
+    #[test]
+    fn test_accept() {
+        #[derive(Default)]
+        struct Outline(Vec<String>);
+
+        impl KeyVisitor for Outline {
+            fn visit_public_key(&mut self, _key: &PublicKey) {
+                self.0.push("key".into());
+            }
+
+            fn visit_contract_id(&mut self, id: &ContractId, delegatable: bool) {
+                self.0.push(format!("{} {}", id, delegatable));
+            }
+
+            fn enter_key_list(&mut self, list: &KeyList) {
+                self.0.push(format!("enter {:?}", list.threshold));
+            }
+
+            fn leave_key_list(&mut self, _list: &KeyList) {
+                self.0.push("leave".into());
+            }
+        }
+
+        let keys = gen_keys();
+        let key = list(
+            Some(1),
+            vec![
+                keys[0].clone().into(),
+                list(None, vec![Key::DelegatableContractId(ContractId::new(0, 0, 9))]),
+            ],
+        );
+
+        let mut outline = Outline::default();
+        key.accept(&mut outline);
+
+        assert_eq!(
+            outline.0,
+            vec!["enter Some(1)", "key", "enter None", "0.0.9 true", "leave", "leave"]
+        );
+    }
+}
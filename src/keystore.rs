@@ -7,9 +7,10 @@ use hmac::{Hmac, Mac, NewMac};
 use rand::Rng;
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use sha2::{Sha256, Sha384};
+use zeroize::Zeroizing;
 
 use crate::keystore_error::KeystoreError;
-use crate::private_key::PrivateKey;
+use crate::private_key::{PrivateKey, PrivateKeyData};
 use crate::KeyError;
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -61,13 +62,13 @@ struct KeyStore {
 //      returns an array that has an encoded serde KeyStore struct
 fn create_keystore(private_key: &[u8], pass: &str) -> Result<Vec<u8>, KeystoreError> {
     let c_iter: u32 = 262144;
-    let mut derived_key: [u8; 32] = [0; 32];
+    let mut derived_key = Zeroizing::new([0u8; 32]);
     let pk_len = private_key.len();
     let salt = rand::thread_rng().gen::<[u8; 32]>();
     let iv = rand::thread_rng().gen::<[u8; 16]>();
 
     // this line takes a hefty 5 seconds to run. yikes.
-    pbkdf2::pbkdf2::<Hmac<Sha256>>(pass.as_bytes(), &salt, c_iter, &mut derived_key);
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(pass.as_bytes(), &salt, c_iter, &mut *derived_key);
 
     // AES-128-CTR with the first half of the derived key and a random IV
     let mut cipher = Aes128Ctr::new_from_slices(&derived_key[0..16], &iv)?;
@@ -111,7 +112,7 @@ fn create_keystore(private_key: &[u8], pass: &str) -> Result<Vec<u8>, KeystoreEr
     Ok(keystore_encode_str.into_bytes())
 }
 
-fn load_keystore(data: &[u8], passphrase: &str) -> Result<Vec<u8>, KeystoreError> {
+fn load_keystore(data: &[u8], passphrase: &str) -> Result<Zeroizing<Vec<u8>>, KeystoreError> {
     let keystore: KeyStore = serde_json::from_slice(&data)?;
 
     if keystore.crypto.kdf != "pbkdf2" {
@@ -129,16 +130,16 @@ fn load_keystore(data: &[u8], passphrase: &str) -> Result<Vec<u8>, KeystoreError
     let salt = hex::decode(keystore.crypto.kdf_params.salt)?;
 
     // derive key
-    let mut derived_key: [u8; 32] = [0; 32];
+    let mut derived_key = Zeroizing::new([0u8; 32]);
     pbkdf2::pbkdf2::<Hmac<Sha256>>(
         passphrase.as_bytes(),
         &salt,
         keystore.crypto.kdf_params.c,
-        &mut derived_key,
+        &mut *derived_key,
     );
 
     // verify mac
-    let mut key_buffer = hex::decode(&keystore.crypto.ciphertext)?;
+    let mut key_buffer = Zeroizing::new(hex::decode(&keystore.crypto.ciphertext)?);
 
     let mut mac = Hmac::<Sha384>::new_from_slice(&derived_key[16..derived_key.len()]).unwrap();
     mac.update(&key_buffer);
@@ -164,7 +165,13 @@ impl PrivateKey {
     }
 
     pub fn to_keystore(&self, passphrase: &str) -> Result<Vec<u8>, KeyError> {
-        Ok(create_keystore(self.keypair.secret.as_bytes(), passphrase)?)
+        // ECDSA(secp256k1) keys are stored as DER so that `from_keystore` can tell them apart
+        let key_data = Zeroizing::new(match &self.data {
+            PrivateKeyData::Ed25519(_) => self.as_ref().to_vec(),
+            PrivateKeyData::EcdsaSecp256k1(_) => self.to_bytes_der(),
+        });
+
+        Ok(create_keystore(&key_data, passphrase)?)
     }
 }
 
@@ -184,7 +191,7 @@ mod tests {
         let keystore = keystore::create_keystore(&hex_string, "hello").unwrap();
         let keypair = keystore::load_keystore(&keystore, "hello").unwrap();
 
-        let keystore_2_str = hex::encode(keypair);
+        let keystore_2_str = hex::encode(&*keypair);
 
         assert_eq!(p_key, keystore_2_str);
     }
@@ -250,7 +257,7 @@ mod tests {
 
         let p_key = PrivateKey::from_keystore(&keystore_js, "hello").unwrap();
 
-        assert_eq!(hex_string, p_key.to_bytes());
+        assert_eq!(hex_string, p_key.to_bytes_raw());
     }
 
     #[cfg(test)]
@@ -1,23 +1,62 @@
+mod asn1;
+mod batch_verification;
 mod bip39_words;
+mod contract_id;
+mod cose;
 mod derive;
 mod entropy;
+mod evm_address;
+mod exposed_secret;
+mod jose;
 mod key;
+mod key_algorithm;
 mod key_error;
+mod key_expression;
 mod key_list;
+mod key_list_error;
+mod key_satisfaction;
+mod key_tree;
 mod keystore;
 mod keystore_error;
 mod legacy_words;
+mod mirror_node_key;
 mod mnemonic;
 mod mnemonic_error;
+mod pem_encryption_options;
+mod pem_key;
 mod private_key;
+mod proto;
 mod public_key;
+mod signature;
+mod signature_map;
+mod signer_requirements;
 mod slip10;
+mod ssh;
+mod threshold_key;
+mod verification_policy;
+mod verify_error;
 
+pub use contract_id::ContractId;
+pub use evm_address::EvmAddress;
+pub use exposed_secret::ExposedSecret;
 pub use key::Key;
+pub use key_algorithm::KeyAlgorithm;
 pub use key_error::KeyError;
 pub use key_list::KeyList;
+pub use key_list_error::KeyListError;
+pub use key_satisfaction::KeySatisfaction;
+pub use key_tree::{KeyVisitor, Leaves};
 pub use keystore_error::KeystoreError;
+pub use mirror_node_key::MirrorNodeKey;
 pub use mnemonic::Mnemonic;
 pub use mnemonic_error::MnemonicError;
+pub use pem_encryption_options::{PemCipher, PemEncryptionOptions, PemKdf};
+pub use pem_key::PemKey;
 pub use private_key::PrivateKey;
 pub use public_key::PublicKey;
+pub use signature::Signature;
+pub use signature_map::{SignatureMap, SignaturePair};
+pub use signer_requirements::SignerRequirements;
+pub use threshold_key::ThresholdKey;
+pub use verification_policy::VerificationPolicy;
+pub use verify_error::VerifyError;
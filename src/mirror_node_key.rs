@@ -0,0 +1,236 @@
+use std::convert::TryFrom;
+
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::key::Key;
+use crate::key_error::KeyError;
+use crate::public_key::PublicKey;
+
+/// A key in the JSON form used by the Hedera™ Mirror Node REST API,
+/// such as `{"_type":"ED25519","key":"0aa8…"}`.
+///
+/// Public keys are written as their raw bytes and every other key, including key lists,
+/// as its HAPI `Key` protobuf encoding under the `ProtobufEncoded` type.
+/// Use this in place of [`Key`] in types deserialized from mirror node responses.
+///
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MirrorNodeKey(pub Key);
+
+#[derive(Deserialize, Serialize)]
+#[serde(tag = "_type", content = "key")]
+enum MirrorNodeKeyJson {
+    #[serde(rename = "ED25519")]
+    Ed25519(String),
+
+    #[serde(rename = "ECDSA_SECP256K1")]
+    EcdsaSecp256k1(String),
+
+    #[serde(rename = "ProtobufEncoded")]
+    ProtobufEncoded(String),
+}
+
+impl TryFrom<&Key> for MirrorNodeKeyJson {
+    type Error = KeyError;
+
+    fn try_from(key: &Key) -> Result<Self, KeyError> {
+        match key {
+            Key::PublicKey(key) if key.is_ed25519() => {
+                Ok(MirrorNodeKeyJson::Ed25519(hex::encode(key.to_bytes_raw())))
+            }
+
+            Key::PublicKey(key) => {
+                Ok(MirrorNodeKeyJson::EcdsaSecp256k1(hex::encode(key.to_bytes_raw())))
+            }
+
+            key => Ok(MirrorNodeKeyJson::ProtobufEncoded(hex::encode(key.to_protobuf_bytes()?))),
+        }
+    }
+}
+
+impl MirrorNodeKeyJson {
+    fn into_key(self) -> Result<Key, KeyError> {
+        match self {
+            MirrorNodeKeyJson::Ed25519(key) => {
+                Ok(PublicKey::from_bytes_ed25519(&hex::decode(key)?)?.into())
+            }
+
+            MirrorNodeKeyJson::EcdsaSecp256k1(key) => {
+                Ok(PublicKey::from_bytes_ecdsa(&hex::decode(key)?)?.into())
+            }
+
+            MirrorNodeKeyJson::ProtobufEncoded(key) => Key::from_protobuf_bytes(&hex::decode(key)?),
+        }
+    }
+}
+
+impl Serialize for MirrorNodeKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MirrorNodeKeyJson::try_from(&self.0).map_err(S::Error::custom)?.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MirrorNodeKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let key = MirrorNodeKeyJson::deserialize(deserializer)?;
+
+        key.into_key().map(MirrorNodeKey).map_err(D::Error::custom)
+    }
+}
+
+impl From<Key> for MirrorNodeKey {
+    fn from(key: Key) -> Self {
+        MirrorNodeKey(key)
+    }
+}
+
+impl From<MirrorNodeKey> for Key {
+    fn from(key: MirrorNodeKey) -> Self {
+        key.0
+    }
+}
+
+impl Key {
+    /// Parses a key from the JSON form used by the Hedera™ Mirror Node REST API.
+    ///
+    /// See [`MirrorNodeKey`].
+    ///
+    pub fn from_mirror_node_json(json: &str) -> Result<Self, KeyError> {
+        Ok(serde_json::from_str::<MirrorNodeKey>(json)?.0)
+    }
+
+    /// Returns this key in the JSON form used by the Hedera™ Mirror Node REST API.
+    ///
+    /// Fails if this key is not valid (see [`Key::validate`]).
+    ///
+    pub fn to_mirror_node_json(&self) -> Result<String, KeyError> {
+        let json = MirrorNodeKeyJson::try_from(self)?;
+
+        // UNWRAP: serializing a `String`-only enum cannot fail
+        Ok(serde_json::to_string(&json).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde::Deserialize;
+
+    use super::MirrorNodeKey;
+    use crate::{ContractId, Key, KeyError, KeyList, PublicKey};
+
+    // `GET /api/v1/accounts/0.0.2`, trimmed
+    const ACCOUNT_RESPONSE: &str = r#"{
+        "account": "0.0.2",
+        "auto_renew_period": 7776000,
+        "key": {
+            "_type": "ED25519",
+            "key": "0aa8e21064c61eab86e2a9c164565b4e7a9a4146106e0a6cd03a8c395a110e92"
+        },
+        "memo": ""
+    }"#;
+
+    // `GET /api/v1/tokens/0.0.1234`, trimmed
+    const TOKEN_RESPONSE: &str = r#"{
+        "token_id": "0.0.1234",
+        "admin_key": {
+            "_type": "ProtobufEncoded",
+            "key": "2a28080112240a221220d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        },
+        "freeze_key": null,
+        "supply_key": {
+            "_type": "ECDSA_SECP256K1",
+            "key": "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        }
+    }"#;
+
+    const ED25519_PUBLIC_KEY: &str =
+        "302a300506032b6570032100d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    const ECDSA_PUBLIC_KEY: &str = "302d300706052b8104000a0322000279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    #[derive(Deserialize)]
+    struct Account {
+        key: MirrorNodeKey,
+    }
+
+    #[derive(Deserialize)]
+    struct Token {
+        admin_key: Option<MirrorNodeKey>,
+        freeze_key: Option<MirrorNodeKey>,
+        supply_key: Option<MirrorNodeKey>,
+    }
+
+    #[test]
+    fn test_account_response() {
+        let account: Account = serde_json::from_str(ACCOUNT_RESPONSE).unwrap();
+
+        match &account.key.0 {
+            Key::PublicKey(key) => assert_eq!(
+                hex::encode(key.to_bytes_raw()),
+                "0aa8e21064c61eab86e2a9c164565b4e7a9a4146106e0a6cd03a8c395a110e92"
+            ),
+
+            key => panic!("expected a public key, found {}", key),
+        }
+    }
+
+    #[test]
+    fn test_token_response() -> Result<(), KeyError> {
+        let token: Token = serde_json::from_str(TOKEN_RESPONSE).unwrap();
+
+        let admin_key =
+            KeyList::with_threshold(vec![PublicKey::from_str(ED25519_PUBLIC_KEY)?.into()], 1)?;
+
+        assert_eq!(token.admin_key.map(Key::from), Some(admin_key.into()));
+        assert_eq!(token.freeze_key, None);
+        assert_eq!(
+            token.supply_key.map(Key::from),
+            Some(PublicKey::from_str(ECDSA_PUBLIC_KEY)?.into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_mirror_node_json() -> Result<(), KeyError> {
+        let ed25519_key = Key::from(PublicKey::from_str(ED25519_PUBLIC_KEY)?);
+        let ecdsa_key = Key::from(PublicKey::from_str(ECDSA_PUBLIC_KEY)?);
+        let list = Key::from(KeyList::with_threshold(vec![ed25519_key.clone()], 1)?);
+
+        assert_eq!(
+            ed25519_key.to_mirror_node_json()?,
+            r#"{"_type":"ED25519","key":"d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"}"#
+        );
+        assert_eq!(
+            ecdsa_key.to_mirror_node_json()?,
+            r#"{"_type":"ECDSA_SECP256K1","key":"0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"}"#
+        );
+        assert_eq!(
+            list.to_mirror_node_json()?,
+            r#"{"_type":"ProtobufEncoded","key":"2a28080112240a221220d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"}"#
+        );
+
+        let contract_key = Key::DelegatableContractId(ContractId::new(0, 0, 1234));
+
+        for key in &[ed25519_key, ecdsa_key, list, contract_key] {
+            assert_eq!(&Key::from_mirror_node_json(&key.to_mirror_node_json()?)?, key);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        for json in &[
+            r#"{"_type":"ED25519","key":"0aa8"}"#,
+            r#"{"_type":"ECDSA_SECP256K1","key":"zz"}"#,
+            r#"{"_type":"RSA_3072","key":"00"}"#,
+            r#"{"_type":"ProtobufEncoded","key":"1a0100"}"#,
+            r#"{"key":"00"}"#,
+        ] {
+            assert!(matches!(Key::from_mirror_node_json(json), Err(KeyError::Json(_))), "{}", json);
+        }
+    }
+}
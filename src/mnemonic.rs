@@ -1,5 +1,5 @@
 use std::convert::TryInto;
-use std::fmt::{Display, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 use std::str::FromStr;
 use std::{fmt, str};
 
@@ -8,15 +8,21 @@ use itertools::Itertools;
 use pbkdf2::pbkdf2;
 use private_key::PrivateKey;
 use sha2::{Digest, Sha256, Sha512};
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::bip39_words::BIP39_WORDS;
+use crate::exposed_secret::ExposedSecret;
 use crate::key_error::KeyError;
 use crate::legacy_words::LEGACY_WORDS;
 use crate::mnemonic_error::MnemonicError;
-use crate::private_key::to_keypair;
+use crate::private_key::{to_keypair, PrivateKeyData};
 use crate::{derive, entropy, private_key, slip10};
 
-#[derive(Debug, Eq, PartialEq)]
+/// A BIP-39 or legacy Hedera™ mnemonic phrase.
+///
+/// `Debug` and `Display` never write out the words; use [`Mnemonic::expose_secret`] for that.
+///
+#[derive(Eq, PartialEq)]
 pub struct Mnemonic {
     words: Box<[String]>,
     legacy: bool,
@@ -102,6 +108,15 @@ impl Mnemonic {
         Ok(self.passphrase_to_private_key(passphrase)?)
     }
 
+    /// Returns a wrapper that writes out the words of this mnemonic with `Display`,
+    /// separated by spaces.
+    ///
+    /// The words are the secret from which keys are recovered; take care where they are written.
+    ///
+    pub fn expose_secret(&self) -> ExposedSecret<'_, Self> {
+        ExposedSecret(self)
+    }
+
     fn validate(&self) -> Result<(), MnemonicError> {
         if self.legacy {
             if self.words.len() != 22 {
@@ -114,7 +129,7 @@ impl Mnemonic {
                 })?;
             }
 
-            let (seed, checksum) = entropy::legacy_1(&*self.words);
+            let (seed, checksum) = entropy::legacy_1(&*self.words)?;
             let new_checksum = entropy::crc_8(&seed);
             if checksum != new_checksum {
                 return Err(MnemonicError::ChecksumMismatch);
@@ -154,7 +169,7 @@ impl Mnemonic {
             for _ in 0..needed_entropy {
                 // UNWRAP: chunks.next() will always give 8 characters
                 let entropy = entropy_chunks.next().unwrap().iter().collect::<String>();
-                entropy_bytes.push(binary_to_byte(&entropy).to_string().parse::<u8>().unwrap());
+                entropy_bytes.push(binary_to_byte(&entropy) as u8);
             }
 
             let new_checksum = derive_check_sum_bits(&entropy_bytes);
@@ -168,14 +183,14 @@ impl Mnemonic {
     }
 
     fn passphrase_to_private_key(&self, passphrase: &str) -> Result<PrivateKey, KeyError> {
-        let input = self.to_string();
-        let salt = format!("mnemonic{}", passphrase);
+        let input = Zeroizing::new(self.expose_secret().to_string());
+        let salt = Zeroizing::new(format!("mnemonic{}", passphrase));
 
-        let mut seed: [u8; 64] = [0; 64];
-        pbkdf2::<Hmac<Sha512>>(input.as_bytes(), salt.as_bytes(), 2048, &mut seed);
+        let mut seed = Zeroizing::new([0u8; 64]);
+        pbkdf2::<Hmac<Sha512>>(input.as_bytes(), salt.as_bytes(), 2048, &mut *seed);
 
         let mut mac = Hmac::<Sha512>::new_from_slice(&b"ed25519 seed"[..]).unwrap();
-        mac.update(&seed);
+        mac.update(&*seed);
 
         let mut digest = mac.finalize().into_bytes();
         let (key_data, chain_code) = digest.split_at_mut(32);
@@ -184,13 +199,13 @@ impl Mnemonic {
             slip10::derive(key_data, chain_code, *index);
         }
 
-        let keypair = to_keypair(&key_data).unwrap();
+        let keypair = to_keypair(&key_data);
 
         // UNWRAP: chain code is guaranteed to be 32 bytes
-        let private_key =
-            PrivateKey { keypair, chain_code: Some(chain_code.as_ref().try_into().unwrap()) };
+        let chain_code = chain_code.as_ref().try_into().unwrap();
+        digest.as_mut_slice().zeroize();
 
-        Ok(private_key)
+        Ok(PrivateKey { data: PrivateKeyData::Ed25519(keypair?), chain_code: Some(chain_code) })
     }
 
     /// Returns a Private Key through legacy mnemonic deriviation.
@@ -198,19 +213,33 @@ impl Mnemonic {
     pub fn to_legacy_private_key(&self) -> Result<PrivateKey, KeyError> {
         let index: i32 = if self.legacy { -1 } else { 0 };
 
-        let seed: [u8; 32] = if self.legacy {
-            entropy::legacy_1(&*self.words).0
+        let seed = Zeroizing::new(if self.legacy {
+            entropy::legacy_1(&*self.words)?.0
         } else {
             entropy::legacy_2(&*self.words)?
-        };
+        });
 
-        let key_data = derive::legacy(&seed, index);
-        let private_key = PrivateKey::from_bytes(&key_data)?;
+        let key_data = Zeroizing::new(derive::legacy(&seed, index));
+        let private_key = PrivateKey::from_bytes_ed25519(&*key_data)?;
 
         Ok(private_key)
     }
 }
 
+/// Wipes the words of the mnemonic, leaving it empty.
+impl Zeroize for Mnemonic {
+    fn zeroize(&mut self) {
+        self.words.zeroize();
+        self.words = Box::default();
+    }
+}
+
+impl Drop for Mnemonic {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 impl FromStr for Mnemonic {
     type Err = MnemonicError;
 
@@ -219,15 +248,52 @@ impl FromStr for Mnemonic {
     }
 }
 
+impl Debug for Mnemonic {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Mnemonic")
+            .field("len", &self.words.len())
+            .field("legacy", &self.legacy)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Writes `[redacted <n>-word mnemonic]`.
 impl Display for Mnemonic {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}", self.words.iter().format(" "))
+        write!(f, "[redacted {}-word mnemonic]", self.words.len())
     }
 }
 
-fn binary_to_byte(bin: &str) -> i32 {
-    let binary_to_byte = i32::from_str_radix(bin, 2).unwrap();
-    return binary_to_byte;
+impl Display for ExposedSecret<'_, Mnemonic> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.words.iter().format(" "))
+    }
+}
+
+/// Serializes as the space-separated words of the mnemonic.
+///
+/// Only available with the `serialize-secrets` feature, to keep mnemonics
+/// from ending up in logs and payloads by accident.
+///
+#[cfg(feature = "serialize-secrets")]
+impl serde::Serialize for Mnemonic {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.words.iter().format(" "))
+    }
+}
+
+/// Deserializes from any string accepted by `FromStr`.
+#[cfg(feature = "serialize-secrets")]
+impl<'de> serde::Deserialize<'de> for Mnemonic {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+fn binary_to_byte(bin: &str) -> u32 {
+    bin.bytes().fold(0, |byte, bit| (byte << 1) | u32::from(bit == b'1'))
 }
 
 fn bytes_to_binary(bytes: &[u8]) -> String {
@@ -250,6 +316,7 @@ fn derive_check_sum_bits(entropy_buffer: &[u8]) -> String {
 
 #[cfg(test)]
 mod tests {
+    use std::mem;
 
     use super::*;
 
@@ -307,7 +374,7 @@ mod tests {
     fn test_passphrase_to_private_key() -> Result<(), KeyError> {
         let mnem = Mnemonic::generate(12).unwrap();
         let private_key = Mnemonic::passphrase_to_private_key(&mnem, "")?;
-        assert_eq!(private_key.to_string().chars().count(), 96);
+        assert_eq!(private_key.to_string_der_secret().chars().count(), 96);
         Ok(())
     }
 
@@ -326,8 +393,8 @@ mod tests {
 
         let legacy_private_key = Mnemonic::to_legacy_private_key(&legacy_mnemonic)?;
         let legacy2_private_key = Mnemonic::to_legacy_private_key(&legacy2_mnemonic)?;
-        assert_eq!(legacy_private_key.to_string(), "302e020100300506032b657004220420882a565ad8cb45643892b5366c1ee1c1ef4a730c5ce821a219ff49b6bf173ddf".to_string());
-        assert_eq!(legacy2_private_key.to_string(), "302e020100300506032b6570042204202b7345f302a10c2a6d55bf8b7af40f125ec41d780957826006d30776f0c441fb".to_string());
+        assert_eq!(legacy_private_key.to_string_der_secret(), "302e020100300506032b657004220420882a565ad8cb45643892b5366c1ee1c1ef4a730c5ce821a219ff49b6bf173ddf".to_string());
+        assert_eq!(legacy2_private_key.to_string_der_secret(), "302e020100300506032b6570042204202b7345f302a10c2a6d55bf8b7af40f125ec41d780957826006d30776f0c441fb".to_string());
 
         Ok(())
     }
@@ -339,7 +406,84 @@ mod tests {
         )
         .unwrap();
         let private_key = Mnemonic::to_private_key(&mnemonic, "")?;
-        assert_eq!(private_key.to_string(), "302e020100300506032b657004220420853f15aecd22706b105da1d709b4ac05b4906170c2b9c7495dff9af49e1391da".to_string());
+        assert_eq!(private_key.to_string_der_secret(), "302e020100300506032b657004220420853f15aecd22706b105da1d709b4ac05b4906170c2b9c7495dff9af49e1391da".to_string());
         Ok(())
     }
+
+    #[cfg(feature = "serialize-secrets")]
+    #[test]
+    fn test_serde() -> Result<(), MnemonicError> {
+        let phrase = "combine quiz usual goddess topple bonus give drive target index love volcano";
+        let mnemonic = Mnemonic::from_str(phrase)?;
+
+        let json = serde_json::to_string(&mnemonic).unwrap();
+
+        assert_eq!(json, format!("\"{}\"", phrase));
+        assert_eq!(serde_json::from_str::<Mnemonic>(&json).unwrap(), mnemonic);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zeroize() -> Result<(), MnemonicError> {
+        let mut mnemonic = Mnemonic::from_str(
+            "combine quiz usual goddess topple bonus give drive target index love volcano",
+        )?;
+
+        let mut words = mem::take(&mut mnemonic.words);
+        let buffers: Vec<(*const u8, usize)> =
+            words.iter().map(|word| (word.as_ptr(), word.capacity())).collect();
+
+        words.zeroize();
+
+        // SAFETY: `words` still owns every buffer, and zeroizing initializes their whole capacity
+        for (buffer, capacity) in buffers {
+            assert!(unsafe { std::slice::from_raw_parts(buffer, capacity) }
+                .iter()
+                .all(|b| *b == 0));
+        }
+
+        assert!(words.iter().all(String::is_empty));
+
+        mnemonic.words = words;
+        mnemonic.zeroize();
+        assert!(mnemonic.words.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_redacted() -> Result<(), MnemonicError> {
+        let phrase = "combine quiz usual goddess topple bonus give drive target index love volcano";
+        let mnemonic = Mnemonic::from_str(phrase)?;
+
+        assert_eq!(mnemonic.to_string(), "[redacted 12-word mnemonic]");
+        assert_eq!(format!("{:?}", mnemonic), "Mnemonic { len: 12, legacy: false, .. }");
+        assert_eq!(mnemonic.expose_secret().to_string(), phrase);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_legacy_private_key_unsupported_length() -> Result<(), MnemonicError> {
+        let mnemonic = Mnemonic::from_str(
+            "combine quiz usual goddess topple bonus give drive target index love volcano",
+        )?;
+
+        assert!(matches!(
+            mnemonic.to_legacy_private_key(),
+            Err(KeyError::Mnemonic(MnemonicError::UnsupportedLength(12)))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        assert!(matches!(Mnemonic::from_str(""), Err(MnemonicError::UnsupportedLength(1))));
+        assert!(matches!(
+            Mnemonic::from_str(&["notaword"; 22].join(" ")),
+            Err(MnemonicError::WordNotFound { index: 0, .. })
+        ));
+    }
 }
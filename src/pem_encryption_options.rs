@@ -0,0 +1,43 @@
+/// How [`PrivateKey::to_encrypted_pem`](crate::PrivateKey::to_encrypted_pem) encrypts a key:
+/// a PKCS#5 PBES2 key derivation function and cipher.
+///
+/// The default, PBKDF2 (HMAC-SHA256) with AES-256-CBC, can be read by OpenSSL and by the
+/// Hedera™ SDKs.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PemEncryptionOptions {
+    pub kdf: PemKdf,
+    pub cipher: PemCipher,
+}
+
+/// A password-based key derivation function for [`PemEncryptionOptions`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PemKdf {
+    /// PBKDF2 with HMAC-SHA256.
+    Pbkdf2 { iterations: u16 },
+
+    /// scrypt, with a cost of `2^log_n` (at most `2^15`).
+    Scrypt { log_n: u8, r: u16, p: u16 },
+}
+
+/// A cipher for [`PemEncryptionOptions`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PemCipher {
+    Aes128Cbc,
+    Aes256Cbc,
+}
+
+impl PemKdf {
+    /// PBKDF2 with the most iterations that can be encoded.
+    pub const PBKDF2: PemKdf = PemKdf::Pbkdf2 { iterations: u16::MAX };
+
+    /// scrypt with the parameters OpenSSL uses by default, which stay within the
+    /// memory limit `openssl pkey` applies when decrypting.
+    pub const SCRYPT: PemKdf = PemKdf::Scrypt { log_n: 14, r: 8, p: 1 };
+}
+
+impl Default for PemEncryptionOptions {
+    fn default() -> Self {
+        Self { kdf: PemKdf::PBKDF2, cipher: PemCipher::Aes256Cbc }
+    }
+}
@@ -0,0 +1,144 @@
+use std::fs;
+use std::path::Path;
+
+use zeroize::Zeroizing;
+
+use crate::key_error::KeyError;
+use crate::private_key::PrivateKey;
+use crate::public_key::PublicKey;
+
+/// A key read from a PEM file by [`PemKey::read_all`].
+#[derive(Debug, PartialEq)]
+pub enum PemKey {
+    Private(PrivateKey),
+    Public(PublicKey),
+}
+
+impl PemKey {
+    /// Reads every key from a PEM document of one or more blocks, such as a key followed
+    /// by its certificate or a bundle of operator keys, along with the label of its block.
+    ///
+    /// `PRIVATE KEY`, `ENCRYPTED PRIVATE KEY` (decrypted with `passphrase`) and `PUBLIC KEY`
+    /// blocks are read; blocks with any other label and text between blocks are skipped.
+    ///
+    pub fn read_all(data: &str, passphrase: &str) -> Result<Vec<(String, PemKey)>, KeyError> {
+        let mut keys = Vec::new();
+
+        for (label, block) in blocks(data)? {
+            let key = match label {
+                "PRIVATE KEY" => PemKey::Private(PrivateKey::from_pem(block, "")?),
+                "ENCRYPTED PRIVATE KEY" => {
+                    PemKey::Private(PrivateKey::from_pem(block, passphrase)?)
+                }
+                "PUBLIC KEY" => PemKey::Public(PublicKey::from_pem(block)?),
+                _ => continue,
+            };
+
+            keys.push((label.to_owned(), key));
+        }
+
+        Ok(keys)
+    }
+
+    /// Reads every key from a PEM file, as by [`PemKey::read_all`].
+    pub fn read_file(
+        path: impl AsRef<Path>,
+        passphrase: &str,
+    ) -> Result<Vec<(String, PemKey)>, KeyError> {
+        PemKey::read_all(&Zeroizing::new(fs::read_to_string(path)?), passphrase)
+    }
+
+    /// Returns the public key of this key.
+    pub fn public_key(&self) -> PublicKey {
+        match self {
+            PemKey::Private(key) => key.public_key(),
+            PemKey::Public(key) => key.clone(),
+        }
+    }
+}
+
+/// Splits a PEM document into its blocks, returning the label and text of each.
+fn blocks(data: &str) -> Result<Vec<(&str, &str)>, KeyError> {
+    let mut blocks = Vec::new();
+    let mut rest = data;
+
+    while let Some(start) = rest.find("-----BEGIN ") {
+        let block = &rest[start..];
+        let header = block.lines().next().unwrap_or_default().trim_end();
+
+        let label = header
+            .strip_prefix("-----BEGIN ")
+            .and_then(|label| label.strip_suffix("-----"))
+            .ok_or(KeyError::Pkcs8(pkcs8::Error::Pem))?;
+
+        let footer = format!("-----END {}-----", label);
+        let end = block.find(&footer).ok_or(KeyError::Pkcs8(pkcs8::Error::Pem))? + footer.len();
+
+        blocks.push((label, &block[..end]));
+        rest = &block[end..];
+    }
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::PemKey;
+    use crate::key_error::KeyError;
+    use crate::pem_encryption_options::PemEncryptionOptions;
+    use crate::private_key::PrivateKey;
+
+    const CERTIFICATE: &str = "-----BEGIN CERTIFICATE-----
+MIIBtjCCAVugAwIBAgITBmyf1XSXNmY/Owua2eiedgPySjAKBggqhkjOPQQDAjA5
+-----END CERTIFICATE-----
+";
+
+    #[test]
+    fn test_read_all() -> Result<(), KeyError> {
+        let private_key = PrivateKey::generate();
+        let ecdsa_key = PrivateKey::generate_ecdsa();
+        let encrypted_pem =
+            ecdsa_key.to_encrypted_pem("passphrase", PemEncryptionOptions::default())?;
+
+        let bundle = format!(
+            "operator key\n{}{}{}\n# public key of the treasury\n{}{}",
+            private_key.to_pem(),
+            CERTIFICATE,
+            encrypted_pem,
+            ecdsa_key.public_key().to_pem(),
+            CERTIFICATE,
+        );
+
+        let keys = PemKey::read_all(&bundle, "passphrase")?;
+        let labels: Vec<_> = keys.iter().map(|(label, _)| label.as_str()).collect();
+
+        assert_eq!(labels, ["PRIVATE KEY", "ENCRYPTED PRIVATE KEY", "PUBLIC KEY"]);
+        assert_eq!(
+            keys[0].1,
+            PemKey::Private(PrivateKey::from_str(&private_key.to_string_der_secret())?)
+        );
+        assert_eq!(
+            keys[1].1,
+            PemKey::Private(PrivateKey::from_str(&ecdsa_key.to_string_der_secret())?)
+        );
+        assert_eq!(keys[2].1, PemKey::Public(ecdsa_key.public_key()));
+        assert_eq!(keys[1].1.public_key(), keys[2].1.public_key());
+
+        assert!(PemKey::read_all(&bundle, "").is_err());
+        assert!(PemKey::read_all(CERTIFICATE, "")?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_all_invalid() {
+        let unterminated = "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEA\n";
+        assert!(matches!(PemKey::read_all(unterminated, ""), Err(KeyError::Pkcs8(_))));
+
+        let mismatched =
+            "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEA\n-----END PRIVATE KEY-----\n";
+        assert!(matches!(PemKey::read_all(mismatched, ""), Err(KeyError::Pkcs8(_))));
+    }
+}
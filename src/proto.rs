@@ -0,0 +1,110 @@
+//! Hand-written `prost` definitions for the subset of the Hedera™ API (HAPI)
+//! protobufs that describe keys and signatures, mirroring `basic_types.proto`.
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct Key {
+    #[prost(oneof = "key::Key", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
+    pub key: Option<key::Key>,
+}
+
+pub(crate) mod key {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub(crate) enum Key {
+        #[prost(message, tag = "1")]
+        ContractId(super::ContractId),
+
+        #[prost(bytes, tag = "2")]
+        Ed25519(Vec<u8>),
+
+        #[prost(bytes, tag = "3")]
+        Rsa3072(Vec<u8>),
+
+        #[prost(bytes, tag = "4")]
+        Ecdsa384(Vec<u8>),
+
+        #[prost(message, tag = "5")]
+        ThresholdKey(super::ThresholdKey),
+
+        #[prost(message, tag = "6")]
+        KeyList(super::KeyList),
+
+        #[prost(bytes, tag = "7")]
+        EcdsaSecp256k1(Vec<u8>),
+
+        #[prost(message, tag = "8")]
+        DelegatableContractId(super::ContractId),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct ThresholdKey {
+    #[prost(uint32, tag = "1")]
+    pub threshold: u32,
+
+    #[prost(message, optional, tag = "2")]
+    pub keys: Option<KeyList>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct KeyList {
+    #[prost(message, repeated, tag = "1")]
+    pub keys: Vec<Key>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct ContractId {
+    #[prost(int64, tag = "1")]
+    pub shard_num: i64,
+
+    #[prost(int64, tag = "2")]
+    pub realm_num: i64,
+
+    #[prost(oneof = "contract_id::Contract", tags = "3, 4")]
+    pub contract: Option<contract_id::Contract>,
+}
+
+pub(crate) mod contract_id {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub(crate) enum Contract {
+        #[prost(int64, tag = "3")]
+        ContractNum(i64),
+
+        #[prost(bytes, tag = "4")]
+        EvmAddress(Vec<u8>),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct SignaturePair {
+    #[prost(bytes, tag = "1")]
+    pub pub_key_prefix: Vec<u8>,
+
+    #[prost(oneof = "signature_pair::Signature", tags = "2, 3, 4, 5, 6")]
+    pub signature: Option<signature_pair::Signature>,
+}
+
+pub(crate) mod signature_pair {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub(crate) enum Signature {
+        #[prost(bytes, tag = "2")]
+        Contract(Vec<u8>),
+
+        #[prost(bytes, tag = "3")]
+        Ed25519(Vec<u8>),
+
+        #[prost(bytes, tag = "4")]
+        Rsa3072(Vec<u8>),
+
+        #[prost(bytes, tag = "5")]
+        Ecdsa384(Vec<u8>),
+
+        #[prost(bytes, tag = "6")]
+        EcdsaSecp256k1(Vec<u8>),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct SignatureMap {
+    #[prost(message, repeated, tag = "1")]
+    pub sig_pair: Vec<SignaturePair>,
+}
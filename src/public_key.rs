@@ -1,27 +1,55 @@
 use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
+use std::path::Path;
 use std::str::FromStr;
-use std::{fmt, str};
+use std::{fmt, fs, str};
 
-use ed25519_dalek::{Signature, Verifier};
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use k256::ecdsa::signature::DigestVerifier;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
 use once_cell::sync::Lazy;
+use pem_rfc7468::LineEnding;
+use sha3::{Digest, Keccak256};
 
+use crate::asn1::SubjectPublicKeyInfo;
+use crate::evm_address::EvmAddress;
+use crate::key_algorithm::KeyAlgorithm;
 use crate::key_error::KeyError;
+use crate::signature::Signature;
+use crate::verification_policy::VerificationPolicy;
+use crate::verify_error::VerifyError;
 
-const DER_PREFIX: &str = "302a300506032b6570032100";
-const DER_PREFIX_BYTES: Lazy<Vec<u8>> = Lazy::new(|| hex::decode(DER_PREFIX).unwrap());
+const ED25519_DER_PREFIX: &str = "302a300506032b6570032100";
+static ED25519_DER_PREFIX_BYTES: Lazy<Vec<u8>> =
+    Lazy::new(|| hex::decode(ED25519_DER_PREFIX).unwrap());
+
+const ECDSA_SECP256K1_DER_PREFIX: &str = "302d300706052b8104000a032200";
+static ECDSA_SECP256K1_DER_PREFIX_BYTES: Lazy<Vec<u8>> =
+    Lazy::new(|| hex::decode(ECDSA_SECP256K1_DER_PREFIX).unwrap());
+
+// SubjectPublicKeyInfo with the `id-ecPublicKey` algorithm and `secp256k1` parameters,
+// as read by OpenSSL and most other tooling
+const ECDSA_SECP256K1_SPKI_PREFIX: &str = "3036301006072a8648ce3d020106052b8104000a032200";
+static ECDSA_SECP256K1_SPKI_PREFIX_BYTES: Lazy<Vec<u8>> =
+    Lazy::new(|| hex::decode(ECDSA_SECP256K1_SPKI_PREFIX).unwrap());
 
 /// A Public Key on the Hedera™ Network
-#[derive(Debug, Eq, PartialEq)]
-pub struct PublicKey(pub(crate) ed25519_dalek::PublicKey);
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicKey(pub(crate) PublicKeyData);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum PublicKeyData {
+    Ed25519(ed25519_dalek::PublicKey),
+    EcdsaSecp256k1(k256::ecdsa::VerifyingKey),
+}
 
 impl Hash for PublicKey {
     fn hash<H>(&self, state: &mut H)
     where
         H: Hasher,
     {
-        self.0.as_bytes().hash(state)
+        self.to_bytes_raw().hash(state)
     }
 }
 
@@ -30,53 +58,294 @@ impl PublicKey {
     ///
     /// # Arguments
     ///
-    /// * `data` - An array of bytes that represent a public key. Can be 32 or 44 bytes in length.
+    /// * `data` - An array of bytes that represent a public key. 32 raw bytes are read as
+    ///   an Ed25519 key and 33 or 65 raw bytes as a (compressed or uncompressed) SEC1
+    ///   ECDSA(secp256k1) key; anything else must be DER.
+    ///
+    /// Prefer [`PublicKey::from_bytes_der`] or one of the algorithm-specific constructors
+    /// when the algorithm of the key is known.
     ///
     pub fn from_bytes(data: &[u8]) -> Result<PublicKey, KeyError> {
-        let public_key = match data.len() {
-            32 => {
-                let public_key = PublicKey(
-                    ed25519_dalek::PublicKey::from_bytes(&data).map_err(KeyError::Signature)?,
-                );
-                public_key
+        match data.len() {
+            32 => PublicKey::from_bytes_ed25519(data),
+            33 | 65 => PublicKey::from_bytes_ecdsa(data),
+            _ => PublicKey::from_bytes_der(data),
+        }
+    }
+
+    /// Returns an Ed25519 public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - 32 raw bytes, or a DER-encoded Ed25519 public key.
+    ///
+    /// Points of small order and non-canonical encodings are rejected, as no key
+    /// generated honestly has either.
+    ///
+    pub fn from_bytes_ed25519(data: &[u8]) -> Result<PublicKey, KeyError> {
+        if data.len() == 32 {
+            let public_key =
+                ed25519_dalek::PublicKey::from_bytes(data).map_err(KeyError::Signature)?;
+
+            // UNWRAP: `ed25519-dalek` has already decompressed the point
+            let point = CompressedEdwardsY::from_slice(data).decompress().unwrap();
+
+            if point.is_small_order() {
+                return Err(KeyError::Ed25519PublicKey("a point of small order"));
+            }
+
+            if point.compress().as_bytes() != data {
+                return Err(KeyError::Ed25519PublicKey("not canonically encoded"));
+            }
+
+            return Ok(PublicKey(PublicKeyData::Ed25519(public_key)));
+        }
+
+        PublicKey::from_bytes_der(data)?.expect_algorithm(KeyAlgorithm::Ed25519)
+    }
+
+    /// Returns an ECDSA(secp256k1) public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - 33 or 65 bytes in compressed or uncompressed SEC1 form,
+    ///   or a DER-encoded ECDSA(secp256k1) public key.
+    ///
+    pub fn from_bytes_ecdsa(data: &[u8]) -> Result<PublicKey, KeyError> {
+        if data.len() == 33 || data.len() == 65 {
+            let public_key =
+                k256::ecdsa::VerifyingKey::from_sec1_bytes(data).map_err(KeyError::Signature)?;
+
+            return Ok(PublicKey(PublicKeyData::EcdsaSecp256k1(public_key)));
+        }
+
+        PublicKey::from_bytes_der(data)?.expect_algorithm(KeyAlgorithm::EcdsaSecp256k1)
+    }
+
+    /// Returns a public key from its DER (SubjectPublicKeyInfo) encoding.
+    ///
+    /// ECDSA(secp256k1) keys can be in the Hedera™ form, with `secp256k1` as the algorithm,
+    /// or the standard `id-ecPublicKey` form, with a compressed or uncompressed point.
+    ///
+    pub fn from_bytes_der(data: &[u8]) -> Result<PublicKey, KeyError> {
+        let info = SubjectPublicKeyInfo::from_der(data)?;
+
+        PublicKey::from_bytes_raw(info.algorithm, info.public_key)
+    }
+
+    /// Returns a public key of `algorithm` from its raw bytes, without falling back to DER.
+    pub(crate) fn from_bytes_raw(
+        algorithm: KeyAlgorithm,
+        data: &[u8],
+    ) -> Result<PublicKey, KeyError> {
+        match (algorithm, data.len()) {
+            (KeyAlgorithm::Ed25519, 32) => PublicKey::from_bytes_ed25519(data),
+            (KeyAlgorithm::EcdsaSecp256k1, 33) | (KeyAlgorithm::EcdsaSecp256k1, 65) => {
+                PublicKey::from_bytes_ecdsa(data)
+            }
+
+            (_, len) => Err(KeyError::Length(len)),
+        }
+    }
+
+    fn expect_algorithm(self, expected: KeyAlgorithm) -> Result<PublicKey, KeyError> {
+        let actual = self.algorithm();
+
+        if actual != expected {
+            return Err(KeyError::AlgorithmMismatch { expected, actual });
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the algorithm of this public key.
+    pub fn algorithm(&self) -> KeyAlgorithm {
+        match &self.0 {
+            PublicKeyData::Ed25519(_) => KeyAlgorithm::Ed25519,
+            PublicKeyData::EcdsaSecp256k1(_) => KeyAlgorithm::EcdsaSecp256k1,
+        }
+    }
+
+    /// Returns `true` if this is an Ed25519 public key.
+    pub fn is_ed25519(&self) -> bool {
+        self.algorithm() == KeyAlgorithm::Ed25519
+    }
+
+    /// Returns `true` if this is an ECDSA(secp256k1) public key.
+    pub fn is_ecdsa(&self) -> bool {
+        self.algorithm() == KeyAlgorithm::EcdsaSecp256k1
+    }
+
+    /// Returns the DER representation of this public key.
+    ///
+    /// Unlike the raw representation, this encoding identifies the algorithm of the key.
+    ///
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes_der()
+    }
+
+    /// Returns the raw byte representation of this public key.
+    ///
+    /// Ed25519 keys are 32 bytes; ECDSA(secp256k1) keys are 33 bytes in compressed SEC1 form.
+    ///
+    pub fn to_bytes_raw(&self) -> Vec<u8> {
+        match &self.0 {
+            PublicKeyData::Ed25519(key) => key.to_bytes().to_vec(),
+            PublicKeyData::EcdsaSecp256k1(key) => key.to_bytes().to_vec(),
+        }
+    }
+
+    /// Returns the DER representation of this public key, using the Hedera™ encoding
+    /// for ECDSA(secp256k1) keys.
+    pub fn to_bytes_der(&self) -> Vec<u8> {
+        let prefix: &[u8] = match &self.0 {
+            PublicKeyData::Ed25519(_) => &ED25519_DER_PREFIX_BYTES,
+            PublicKeyData::EcdsaSecp256k1(_) => &ECDSA_SECP256K1_DER_PREFIX_BYTES,
+        };
+
+        [prefix, &self.to_bytes_raw()].concat()
+    }
+
+    /// Returns the 65-byte uncompressed SEC1 representation of this public key.
+    ///
+    /// Returns `None` for Ed25519 keys, which have no uncompressed form.
+    ///
+    pub fn to_bytes_uncompressed(&self) -> Option<Vec<u8>> {
+        match &self.0 {
+            PublicKeyData::Ed25519(_) => None,
+            PublicKeyData::EcdsaSecp256k1(key) => {
+                Some(key.to_encoded_point(false).as_bytes().to_vec())
             }
+        }
+    }
 
-            44 if data.starts_with(&DER_PREFIX_BYTES) => {
-                let public_key = PublicKey(
-                    ed25519_dalek::PublicKey::from_bytes(&data[12..44])
-                        .map_err(KeyError::Signature)?,
-                );
-                public_key
+    /// Returns the affine `x` and `y` coordinates of an ECDSA(secp256k1) public key,
+    /// or `None` for an Ed25519 key.
+    pub(crate) fn to_affine_coordinates(&self) -> Option<([u8; 32], [u8; 32])> {
+        match &self.0 {
+            PublicKeyData::Ed25519(_) => None,
+
+            PublicKeyData::EcdsaSecp256k1(key) => {
+                // `0x04 || x || y`
+                let point = key.to_encoded_point(false);
+                let (mut x, mut y) = ([0; 32], [0; 32]);
+
+                x.copy_from_slice(&point.as_bytes()[1..33]);
+                y.copy_from_slice(&point.as_bytes()[33..]);
+
+                Some((x, y))
             }
+        }
+    }
 
-            _ => {
-                return Err(KeyError::Length(data.len()));
+    /// Returns the EVM address of this ECDSA(secp256k1) public key.
+    ///
+    /// Ed25519 keys have no EVM address and return [`KeyError::AlgorithmMismatch`].
+    ///
+    pub fn to_evm_address(&self) -> Result<EvmAddress, KeyError> {
+        match self.to_bytes_uncompressed() {
+            Some(point) => Ok(EvmAddress::from_uncompressed_point(&point)),
+
+            None => Err(KeyError::AlgorithmMismatch {
+                expected: KeyAlgorithm::EcdsaSecp256k1,
+                actual: self.algorithm(),
+            }),
+        }
+    }
+
+    /// Returns a public key from a `PUBLIC KEY` PEM document.
+    pub fn from_pem(data: &str) -> Result<PublicKey, KeyError> {
+        match pem_rfc7468::decode_vec(data.as_bytes()) {
+            Ok(("PUBLIC KEY", der)) => PublicKey::from_bytes_der(&der),
+            Ok((label, _)) => Err(KeyError::PemLabel(label.to_owned())),
+            Err(_) => Err(KeyError::Pkcs8(pkcs8::Error::Pem)),
+        }
+    }
+
+    /// Returns a public key from a file holding a `PUBLIC KEY` PEM document.
+    pub fn from_pem_file(path: impl AsRef<Path>) -> Result<PublicKey, KeyError> {
+        PublicKey::from_pem(&fs::read_to_string(path)?)
+    }
+
+    /// Returns this public key as a `PUBLIC KEY` PEM document.
+    ///
+    /// ECDSA(secp256k1) keys are written in the standard `id-ecPublicKey` form that
+    /// OpenSSL reads, rather than the form of [`PublicKey::to_bytes_der`].
+    ///
+    pub fn to_pem(&self) -> String {
+        let der = match &self.0 {
+            PublicKeyData::Ed25519(_) => self.to_bytes_der(),
+            PublicKeyData::EcdsaSecp256k1(_) => {
+                [&ECDSA_SECP256K1_SPKI_PREFIX_BYTES, &*self.to_bytes_raw()].concat()
             }
         };
 
-        Ok(public_key)
+        // UNWRAP: the label is valid and the output buffer is sized by `pem_rfc7468`
+        pem_rfc7468::encode_string("PUBLIC KEY", LineEnding::default(), &der).unwrap()
     }
 
-    /// Returns a byte representation of this public key.
-    pub fn to_bytes(&self) -> [u8; ed25519_dalek::PUBLIC_KEY_LENGTH] {
-        self.0.to_bytes()
+    /// Writes this public key to a file as a `PUBLIC KEY` PEM document,
+    /// replacing the file if it exists.
+    pub fn to_pem_file(&self, path: impl AsRef<Path>) -> Result<(), KeyError> {
+        fs::write(path, self.to_pem())?;
+
+        Ok(())
     }
 
     /// Verify a signature on a message with this public key.
-    pub fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
-        let signature = if let Ok(signature) = Signature::try_from(signature) {
-            signature
-        } else {
-            return false;
-        };
+    ///
+    /// Ed25519 signatures are verified under [`VerificationPolicy::Legacy`].
+    ///
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> Result<(), VerifyError> {
+        self.verify_with_policy(message, signature, VerificationPolicy::Legacy)
+    }
+
+    /// Verify a signature on a message with this public key, accepting the Ed25519
+    /// signatures that `policy` does.
+    ///
+    /// ECDSA(secp256k1) signatures are over the Keccak-256 hash of the message.
+    ///
+    pub fn verify_with_policy(
+        &self,
+        message: &[u8],
+        signature: &Signature,
+        policy: VerificationPolicy,
+    ) -> Result<(), VerifyError> {
+        if signature.algorithm() != self.algorithm() {
+            return Err(VerifyError::AlgorithmMismatch {
+                expected: self.algorithm(),
+                actual: signature.algorithm(),
+            });
+        }
+
+        match &self.0 {
+            PublicKeyData::Ed25519(key) => {
+                policy.verify_ed25519(key, message, &signature.to_bytes_raw())
+            }
+
+            PublicKeyData::EcdsaSecp256k1(key) => {
+                let signature = k256::ecdsa::Signature::try_from(signature.as_ref())
+                    .map_err(|_| VerifyError::InvalidScalar)?;
+
+                key.verify_digest(Keccak256::new().chain(message), &signature)
+                    .map_err(|_| VerifyError::Mismatch)
+            }
+        }
+    }
 
-        self.0.verify(message, &signature).is_ok()
+    /// Returns `true` if the raw bytes of a signature by a key of this algorithm are
+    /// a valid signature on a message, as by [`PublicKey::verify`].
+    pub(crate) fn verify_raw(&self, message: &[u8], signature: &[u8]) -> bool {
+        match Signature::from_bytes_raw(self.algorithm(), signature) {
+            Ok(signature) => self.verify(message, &signature).is_ok(),
+            Err(_) => false,
+        }
     }
 }
 
 impl Display for PublicKey {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}{}", DER_PREFIX, hex::encode(self.0.as_bytes()))
+        write!(f, "{}", hex::encode(self.to_bytes_der()))
     }
 }
 
@@ -91,6 +360,24 @@ impl FromStr for PublicKey {
     }
 }
 
+/// Serializes as the hex-encoded DER of the key, like `Display`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for PublicKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes from any string accepted by `FromStr`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PublicKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::hash_map::DefaultHasher;
@@ -98,16 +385,25 @@ mod tests {
     use std::str::FromStr;
 
     use super::{KeyError, PublicKey};
+    use crate::{KeyAlgorithm, PrivateKey, Signature, VerificationPolicy, VerifyError};
 
     const PUBLIC_KEY_BYTES: &[u8] = &[
         215, 90, 152, 1, 130, 177, 10, 183, 213, 75, 254, 211, 201, 100, 7, 58, 14, 225, 114, 243,
         218, 166, 35, 37, 175, 2, 26, 104, 247, 7, 81, 26,
     ];
 
+    const PUBLIC_KEY_DER: &str =
+        "302a300506032b6570032100d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    const ECDSA_PUBLIC_KEY_STR: &str = "302d300706052b8104000a0322000279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+    const ECDSA_PUBLIC_KEY_SPKI: &str = "3036301006072a8648ce3d020106052b8104000a0322000279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+    const ECDSA_PUBLIC_KEY_COMPRESSED: &str =
+        "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+    const ECDSA_PUBLIC_KEY_UNCOMPRESSED: &str = "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
     #[test]
     fn parse_from_bytes() -> Result<(), KeyError> {
         let public_key = gen_public_key()?;
-        assert_eq!(&public_key.to_bytes(), PUBLIC_KEY_BYTES);
+        assert_eq!(&public_key.to_bytes_raw(), PUBLIC_KEY_BYTES);
 
         Ok(())
     }
@@ -118,7 +414,7 @@ mod tests {
 
         let key_to_bytes = PublicKey::to_bytes(&public_key);
 
-        assert_eq!(key_to_bytes, PUBLIC_KEY_BYTES);
+        assert_eq!(hex::encode(key_to_bytes), PUBLIC_KEY_DER);
 
         Ok(())
     }
@@ -129,15 +425,19 @@ mod tests {
         let message = b"hello, world";
         let signature = &[157, 4, 191, 237, 123, 170, 151, 200, 13, 41, 166, 174];
 
-        println!("{}", PublicKey::verify(&public_key, message, signature));
+        assert!(!public_key.verify_raw(message, signature));
+        assert!(matches!(
+            Signature::from_bytes_raw(KeyAlgorithm::Ed25519, signature),
+            Err(KeyError::InvalidSignature(_))
+        ));
+
         Ok(())
     }
 
     #[test]
     fn test_to_string() -> Result<(), KeyError> {
         let public_key = gen_public_key()?;
-        let test_string = "302a300506032b6570032100d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
-        assert_eq!(public_key.to_string(), test_string);
+        assert_eq!(public_key.to_string(), PUBLIC_KEY_DER);
         Ok(())
     }
 
@@ -163,6 +463,231 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_ecdsa_encodings() -> Result<(), KeyError> {
+        let public_key = PublicKey::from_str(ECDSA_PUBLIC_KEY_STR)?;
+
+        assert_eq!(hex::encode(public_key.to_bytes_raw()), ECDSA_PUBLIC_KEY_COMPRESSED);
+        assert_eq!(
+            hex::encode(public_key.to_bytes_uncompressed().unwrap()),
+            ECDSA_PUBLIC_KEY_UNCOMPRESSED
+        );
+        assert_eq!(PublicKey::from_str(ECDSA_PUBLIC_KEY_COMPRESSED)?, public_key);
+        assert_eq!(PublicKey::from_str(ECDSA_PUBLIC_KEY_UNCOMPRESSED)?, public_key);
+        assert_eq!(PublicKey::from_str(ECDSA_PUBLIC_KEY_SPKI)?, public_key);
+        assert_eq!(public_key.to_string(), ECDSA_PUBLIC_KEY_STR);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bytes_der() -> Result<(), KeyError> {
+        let public_key = PublicKey::from_str(ECDSA_PUBLIC_KEY_STR)?;
+        let uncompressed_spki = format!(
+            "3056301006072a8648ce3d020106052b8104000a034200{}",
+            ECDSA_PUBLIC_KEY_UNCOMPRESSED
+        );
+
+        assert_eq!(PublicKey::from_str(&uncompressed_spki)?, public_key);
+
+        assert!(matches!(
+            PublicKey::from_str(&format!("{}00", PUBLIC_KEY_DER)),
+            Err(KeyError::Der(_))
+        ));
+
+        // an Ed25519 algorithm identifier around an ECDSA key
+        let mislabeled = format!("302b300506032b6570032200{}", ECDSA_PUBLIC_KEY_COMPRESSED);
+        assert!(matches!(PublicKey::from_str(&mislabeled), Err(KeyError::Length(33))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pem() -> Result<(), KeyError> {
+        let public_key = gen_public_key()?;
+        let pem = public_key.to_pem();
+
+        assert_eq!(
+            pem,
+            "-----BEGIN PUBLIC KEY-----\n\
+             MCowBQYDK2VwAyEA11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=\n\
+             -----END PUBLIC KEY-----\n"
+        );
+        assert_eq!(PublicKey::from_pem(&pem)?, public_key);
+
+        let ecdsa_key = PublicKey::from_str(ECDSA_PUBLIC_KEY_STR)?;
+        assert!(ecdsa_key.to_pem().contains("MDYwEAYHKoZIzj0CAQYFK4EEAAoDIgAC"));
+        assert_eq!(PublicKey::from_pem(&ecdsa_key.to_pem())?, ecdsa_key);
+
+        assert!(matches!(
+            PublicKey::from_pem(&pem.replace("PUBLIC KEY", "PRIVATE KEY")),
+            Err(KeyError::PemLabel(label)) if label == "PRIVATE KEY"
+        ));
+        assert!(matches!(PublicKey::from_pem("not PEM"), Err(KeyError::Pkcs8(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pem_file() -> Result<(), KeyError> {
+        let public_key = gen_public_key()?;
+        let path =
+            std::env::temp_dir().join(format!("hedera-crypto-{}.pub.pem", std::process::id()));
+
+        public_key.to_pem_file(&path)?;
+        let read = PublicKey::from_pem_file(&path);
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(read?, public_key);
+        assert!(matches!(PublicKey::from_pem_file(&path), Err(KeyError::Io(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ed25519_has_no_uncompressed_form() -> Result<(), KeyError> {
+        assert_eq!(gen_public_key()?.to_bytes_uncompressed(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_algorithm() -> Result<(), KeyError> {
+        let ed25519_key = gen_public_key()?;
+        let ecdsa_key = PublicKey::from_str(ECDSA_PUBLIC_KEY_STR)?;
+
+        assert_eq!(ed25519_key.algorithm(), KeyAlgorithm::Ed25519);
+        assert!(ed25519_key.is_ed25519());
+        assert_eq!(ecdsa_key.algorithm(), KeyAlgorithm::EcdsaSecp256k1);
+        assert!(ecdsa_key.is_ecdsa());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bytes_explicit_algorithm() -> Result<(), KeyError> {
+        let ed25519_der = hex::decode(PUBLIC_KEY_DER)?;
+        let ecdsa_der = hex::decode(ECDSA_PUBLIC_KEY_STR)?;
+
+        assert_eq!(PublicKey::from_bytes_ed25519(&ed25519_der)?, gen_public_key()?);
+        assert_eq!(
+            PublicKey::from_bytes_der(&ecdsa_der)?.algorithm(),
+            KeyAlgorithm::EcdsaSecp256k1
+        );
+
+        assert!(matches!(
+            PublicKey::from_bytes_ecdsa(&ed25519_der),
+            Err(KeyError::AlgorithmMismatch {
+                expected: KeyAlgorithm::EcdsaSecp256k1,
+                actual: KeyAlgorithm::Ed25519
+            })
+        ));
+        assert!(matches!(
+            PublicKey::from_bytes_ed25519(&ecdsa_der),
+            Err(KeyError::AlgorithmMismatch { .. })
+        ));
+        assert!(PublicKey::from_bytes_der(PUBLIC_KEY_BYTES).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_with_policy() -> Result<(), KeyError> {
+        let public_key = gen_public_key()?;
+        let message = b"hello, world";
+
+        let verify = |signature: &str, policy| {
+            let signature = format!("ed25519:{}", signature).parse::<Signature>().unwrap();
+            public_key.verify_with_policy(message, &signature, policy)
+        };
+
+        let honest = "f7a8b9e17aa4e56e79fc85e44e97a9d5b534679c0ce7fbda623e542116622e930e4829c149d5c06d5c01cfc8d4f590b5307e52ece3ec2f97691a622896b9a107";
+
+        // `R` is the identity, which has small order
+        let identity = "01000000000000000000000000000000000000000000000000000000000000003f5d30a435759cecc8ce0d40200bc8057cbc0d7361baecbbe1674d3079ef140b";
+
+        // `R` is the identity, encoded with `y = p + 1`
+        let non_canonical = "eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7feeca9adb9c9468a7e0981aa23014114f134891600652c308a475dbcee9ed9b09";
+
+        // `R` has a component of order 8, which only the cofactor cancels out
+        let torsion = "40a4cf5c611d737a2f1c6fcca84d9cabfed80987f186a5a6c57e629bff1af27fbd0f9e4553a678cb61530032758f2221f00f830919cd5ffee6898b54d292cf09";
+
+        for policy in
+            [VerificationPolicy::Strict, VerificationPolicy::Zip215, VerificationPolicy::Legacy]
+        {
+            assert_eq!(verify(honest, policy), Ok(()));
+            assert_eq!(
+                verify(&format!("{}{}", &honest[..64], "ff".repeat(32)), policy),
+                Err(VerifyError::InvalidScalar)
+            );
+        }
+
+        assert_eq!(verify(identity, VerificationPolicy::Strict), Err(VerifyError::SmallOrderPoint));
+        assert_eq!(verify(identity, VerificationPolicy::Zip215), Ok(()));
+        assert_eq!(verify(identity, VerificationPolicy::Legacy), Ok(()));
+
+        assert_eq!(
+            verify(non_canonical, VerificationPolicy::Strict),
+            Err(VerifyError::NonCanonicalPoint)
+        );
+        assert_eq!(verify(non_canonical, VerificationPolicy::Zip215), Ok(()));
+        assert_eq!(verify(non_canonical, VerificationPolicy::Legacy), Err(VerifyError::Mismatch));
+
+        assert_eq!(verify(torsion, VerificationPolicy::Strict), Err(VerifyError::Mismatch));
+        assert_eq!(verify(torsion, VerificationPolicy::Zip215), Ok(()));
+        assert_eq!(verify(torsion, VerificationPolicy::Legacy), Err(VerifyError::Mismatch));
+
+        assert!(public_key.verify_raw(message, &hex::decode(identity)?));
+        assert!(!public_key.verify_raw(message, &hex::decode(torsion)?));
+        assert!(!public_key.verify_raw(message, &hex::decode(&honest[..124])?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_with_policy_ecdsa() -> Result<(), KeyError> {
+        let private_key = PrivateKey::generate_ecdsa();
+        let signature = private_key.sign(b"hello, world");
+
+        for policy in
+            [VerificationPolicy::Strict, VerificationPolicy::Zip215, VerificationPolicy::Legacy]
+        {
+            let public_key = private_key.public_key();
+
+            assert_eq!(public_key.verify_with_policy(b"hello, world", &signature, policy), Ok(()));
+            assert_eq!(
+                public_key.verify_with_policy(b"hello, World", &signature, policy),
+                Err(VerifyError::Mismatch)
+            );
+            assert_eq!(
+                gen_public_key()?.verify_with_policy(b"hello, world", &signature, policy),
+                Err(VerifyError::AlgorithmMismatch {
+                    expected: KeyAlgorithm::Ed25519,
+                    actual: KeyAlgorithm::EcdsaSecp256k1
+                })
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bytes_ed25519_weak_keys() -> Result<(), KeyError> {
+        // the identity, a point of order 8, and `y = p + 3`
+        let small_order = "0100000000000000000000000000000000000000000000000000000000000000";
+        let order_8 = "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a";
+        let non_canonical = "f0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f";
+
+        for key in [small_order, order_8, non_canonical] {
+            assert!(matches!(
+                PublicKey::from_bytes_ed25519(&hex::decode(key)?),
+                Err(KeyError::Ed25519PublicKey(_))
+            ));
+        }
+
+        Ok(())
+    }
+
     fn gen_public_key() -> Result<PublicKey, KeyError> {
         let public_key = PublicKey::from_bytes(&PUBLIC_KEY_BYTES)?;
         Ok(public_key)
@@ -173,4 +698,18 @@ mod tests {
         t.hash(&mut s);
         s.finish()
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() -> Result<(), KeyError> {
+        let public_key = gen_public_key()?;
+        let json = serde_json::to_string(&public_key).unwrap();
+
+        assert_eq!(json, format!("\"{}\"", PUBLIC_KEY_DER));
+        assert_eq!(serde_json::from_str::<PublicKey>(&json).unwrap(), public_key);
+
+        assert!(serde_json::from_str::<PublicKey>("\"302a\"").is_err());
+
+        Ok(())
+    }
 }
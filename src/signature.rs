@@ -0,0 +1,237 @@
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use base64ct::{Base64, Encoding};
+
+use crate::key_algorithm::KeyAlgorithm;
+use crate::key_error::KeyError;
+
+const ED25519_PREFIX: &str = "ed25519";
+const ECDSA_SECP256K1_PREFIX: &str = "ecdsa-secp256k1";
+
+/// A signature made by a [`PrivateKey`](crate::PrivateKey), along with the algorithm
+/// of the key that made it.
+///
+/// In text, a signature is its algorithm followed by its raw bytes in hex or base64,
+/// as in `ed25519:<hex>` or `ecdsa-secp256k1:<base64>`.
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Signature {
+    algorithm: KeyAlgorithm,
+    bytes: [u8; 64],
+}
+
+impl Signature {
+    pub(crate) fn new(algorithm: KeyAlgorithm, bytes: [u8; 64]) -> Self {
+        Self { algorithm, bytes }
+    }
+
+    /// Returns a signature of `algorithm` from its 64 raw bytes: `R || S` for Ed25519
+    /// and `r || s` for ECDSA(secp256k1).
+    pub fn from_bytes_raw(algorithm: KeyAlgorithm, data: &[u8]) -> Result<Signature, KeyError> {
+        let bytes = <[u8; 64]>::try_from(data)
+            .map_err(|_| KeyError::InvalidSignature("expected 64 bytes"))?;
+
+        Ok(Signature::new(algorithm, bytes))
+    }
+
+    /// Returns an ECDSA(secp256k1) signature from its DER (`ECDSA-Sig-Value`) encoding.
+    pub fn from_bytes_der(data: &[u8]) -> Result<Signature, KeyError> {
+        let signature = k256::ecdsa::Signature::from_der(data).map_err(KeyError::Signature)?;
+
+        Signature::from_bytes_raw(KeyAlgorithm::EcdsaSecp256k1, signature.as_ref())
+    }
+
+    /// Returns the algorithm of the key that made this signature.
+    pub fn algorithm(&self) -> KeyAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns the 64 raw bytes of this signature.
+    pub fn to_bytes_raw(&self) -> [u8; 64] {
+        self.bytes
+    }
+
+    /// Returns the DER (`ECDSA-Sig-Value`) encoding of an ECDSA(secp256k1) signature.
+    ///
+    /// Returns `None` for Ed25519 signatures, which have no DER encoding, and for
+    /// ECDSA signatures whose `r` or `s` is out of range.
+    ///
+    pub fn to_bytes_der(&self) -> Option<Vec<u8>> {
+        match self.algorithm {
+            KeyAlgorithm::Ed25519 => None,
+
+            KeyAlgorithm::EcdsaSecp256k1 => {
+                let signature = k256::ecdsa::Signature::try_from(&self.bytes[..]).ok()?;
+
+                Some(signature.to_der().as_bytes().to_vec())
+            }
+        }
+    }
+
+    /// Returns this signature as text with its raw bytes in base64, such as
+    /// `ed25519:<base64>`.
+    pub fn to_string_base64(&self) -> String {
+        format!("{}:{}", prefix(self.algorithm), Base64::encode_string(&self.bytes))
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl Display for Signature {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}:{}", prefix(self.algorithm), hex::encode(self.bytes))
+    }
+}
+
+impl FromStr for Signature {
+    type Err = KeyError;
+
+    fn from_str(text: &str) -> Result<Self, KeyError> {
+        let (algorithm, encoded) = match text.find(':') {
+            Some(index) => (&text[..index], &text[index + 1..]),
+            None => return Err(KeyError::InvalidSignature("missing algorithm prefix")),
+        };
+
+        let algorithm = match algorithm {
+            ED25519_PREFIX => KeyAlgorithm::Ed25519,
+            ECDSA_SECP256K1_PREFIX => KeyAlgorithm::EcdsaSecp256k1,
+            _ => return Err(KeyError::UnsupportedAlgorithm(algorithm.to_owned())),
+        };
+
+        // 64 bytes are 128 characters in hex and 88 in base64
+        let bytes = if encoded.len() == 128 {
+            hex::decode(encoded)?
+        } else {
+            Base64::decode_vec(encoded).map_err(|_| KeyError::InvalidSignature("invalid base64"))?
+        };
+
+        Signature::from_bytes_raw(algorithm, &bytes)
+    }
+}
+
+/// Serializes as text with the raw bytes in hex, like `Display`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Signature {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes from any string accepted by `FromStr`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Signature {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+fn prefix(algorithm: KeyAlgorithm) -> &'static str {
+    match algorithm {
+        KeyAlgorithm::Ed25519 => ED25519_PREFIX,
+        KeyAlgorithm::EcdsaSecp256k1 => ECDSA_SECP256K1_PREFIX,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Signature;
+    use crate::key_algorithm::KeyAlgorithm;
+    use crate::key_error::KeyError;
+    use crate::private_key::PrivateKey;
+
+    // the first test vector of RFC 8032, over an empty message
+    const ED25519_SIGNATURE: &str = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+
+    #[test]
+    fn test_to_string() -> Result<(), KeyError> {
+        let signature =
+            Signature::from_bytes_raw(KeyAlgorithm::Ed25519, &hex::decode(ED25519_SIGNATURE)?)?;
+
+        assert_eq!(signature.to_string(), format!("ed25519:{}", ED25519_SIGNATURE));
+        assert_eq!(
+            signature.to_string_base64(),
+            "ed25519:5VZDAMNgrHKQhuLMgG6CioSHfx645dl02HPgZSJJAVVfuIIVkKM7rMYeOXAc+bRr0lv18Flbvi\
+             RlUUFDjnoQCw=="
+        );
+
+        assert_eq!(signature.to_string().parse::<Signature>()?, signature);
+        assert_eq!(signature.to_string_base64().parse::<Signature>()?, signature);
+
+        let private_key = PrivateKey::generate_ecdsa();
+        let signature = private_key.sign(b"hello, world");
+
+        assert!(signature.to_string().starts_with("ecdsa-secp256k1:"));
+        assert_eq!(signature.to_string().parse::<Signature>()?, signature);
+        assert_eq!(signature.to_string_base64().parse::<Signature>()?, signature);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        assert!(matches!(
+            ED25519_SIGNATURE.parse::<Signature>(),
+            Err(KeyError::InvalidSignature(_))
+        ));
+        assert!(matches!(
+            format!("rsa:{}", ED25519_SIGNATURE).parse::<Signature>(),
+            Err(KeyError::UnsupportedAlgorithm(algorithm)) if algorithm == "rsa"
+        ));
+        assert!(matches!(
+            format!("ed25519:{}", &ED25519_SIGNATURE[2..]).parse::<Signature>(),
+            Err(KeyError::InvalidSignature(_))
+        ));
+        assert!(matches!(
+            format!("ed25519:{}zz", &ED25519_SIGNATURE[2..]).parse::<Signature>(),
+            Err(KeyError::Hex(_))
+        ));
+    }
+
+    #[test]
+    fn test_der() -> Result<(), KeyError> {
+        // `r = 1` and `s = 1`
+        let der = hex::decode("3006020101020101")?;
+        let signature = Signature::from_bytes_der(&der)?;
+
+        assert_eq!(signature.algorithm(), KeyAlgorithm::EcdsaSecp256k1);
+        assert_eq!(hex::encode(signature.to_bytes_raw()), format!("{:064x}{:064x}", 1, 1));
+        assert_eq!(signature.to_bytes_der(), Some(der));
+
+        let private_key = PrivateKey::generate_ecdsa();
+        let signature = private_key.sign(b"hello, world");
+        let der = signature.to_bytes_der().unwrap();
+
+        assert_eq!(Signature::from_bytes_der(&der)?, signature);
+        assert_eq!(private_key.public_key().verify(b"hello, world", &signature), Ok(()));
+
+        let ed25519_signature = PrivateKey::generate().sign(b"hello, world");
+        assert_eq!(ed25519_signature.to_bytes_der(), None);
+
+        assert!(matches!(Signature::from_bytes_der(&der[1..]), Err(KeyError::Signature(_))));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() -> Result<(), KeyError> {
+        let signature = PrivateKey::generate().sign(b"hello, world");
+        let json = serde_json::to_string(&signature)?;
+
+        assert_eq!(json, format!("\"{}\"", signature));
+        assert_eq!(serde_json::from_str::<Signature>(&json)?, signature);
+
+        let json = format!("\"{}\"", signature.to_string_base64());
+        assert_eq!(serde_json::from_str::<Signature>(&json)?, signature);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,408 @@
+use prost::Message;
+
+use crate::key_algorithm::KeyAlgorithm;
+use crate::key_error::KeyError;
+use crate::private_key::PrivateKey;
+use crate::proto;
+use crate::public_key::PublicKey;
+
+/// A signature together with a prefix of the public key that made it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignaturePair {
+    pub_key_prefix: Vec<u8>,
+    signature: Vec<u8>,
+    algorithm: KeyAlgorithm,
+}
+
+impl SignaturePair {
+    /// Returns the leading bytes of the raw public key that made this signature.
+    pub fn pub_key_prefix(&self) -> &[u8] {
+        &self.pub_key_prefix
+    }
+
+    /// Returns the signature bytes.
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    /// Returns the algorithm of the signature.
+    pub fn algorithm(&self) -> KeyAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns `true` if `public_key` could have made this signature,
+    /// judging only by its algorithm and prefix.
+    pub fn matches(&self, public_key: &PublicKey) -> bool {
+        public_key.algorithm() == self.algorithm
+            && public_key.to_bytes_raw().starts_with(&self.pub_key_prefix)
+    }
+
+    fn to_protobuf(&self) -> proto::SignaturePair {
+        let signature = match self.algorithm {
+            KeyAlgorithm::Ed25519 => proto::signature_pair::Signature::Ed25519,
+            KeyAlgorithm::EcdsaSecp256k1 => proto::signature_pair::Signature::EcdsaSecp256k1,
+        };
+
+        proto::SignaturePair {
+            pub_key_prefix: self.pub_key_prefix.clone(),
+            signature: Some(signature(self.signature.clone())),
+        }
+    }
+
+    fn from_protobuf(pair: proto::SignaturePair) -> Result<Self, KeyError> {
+        let (algorithm, signature) = match pair.signature {
+            Some(proto::signature_pair::Signature::Ed25519(signature)) => {
+                (KeyAlgorithm::Ed25519, signature)
+            }
+
+            Some(proto::signature_pair::Signature::EcdsaSecp256k1(signature)) => {
+                (KeyAlgorithm::EcdsaSecp256k1, signature)
+            }
+
+            Some(proto::signature_pair::Signature::Contract(_)) => {
+                return Err(KeyError::UnsupportedKeyType("contract signature"));
+            }
+
+            Some(proto::signature_pair::Signature::Rsa3072(_)) => {
+                return Err(KeyError::UnsupportedKeyType("RSA-3072"));
+            }
+
+            Some(proto::signature_pair::Signature::Ecdsa384(_)) => {
+                return Err(KeyError::UnsupportedKeyType("ECDSA(P-384)"));
+            }
+
+            None => return Err(KeyError::UnsupportedKeyType("empty signature")),
+        };
+
+        Ok(Self { pub_key_prefix: pair.pub_key_prefix, signature, algorithm })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Signer {
+    /// The full public key, known for signatures added to this map.
+    PublicKey(PublicKey),
+
+    /// A public key prefix, all that is known for signatures decoded from protobuf.
+    Prefix(Vec<u8>, KeyAlgorithm),
+}
+
+impl Signer {
+    /// Returns `true` if this signer could be `public_key`.
+    fn matches(&self, public_key: &PublicKey) -> bool {
+        match self {
+            Signer::PublicKey(key) => key == public_key,
+
+            Signer::Prefix(prefix, algorithm) => {
+                public_key.algorithm() == *algorithm
+                    && public_key.to_bytes_raw().starts_with(prefix)
+            }
+        }
+    }
+
+    fn key_bytes(&self) -> Vec<u8> {
+        match self {
+            Signer::PublicKey(key) => key.to_bytes_raw(),
+            Signer::Prefix(prefix, _) => prefix.clone(),
+        }
+    }
+}
+
+/// The signatures on a Hedera™ transaction, each identified by a prefix of its signer's
+/// public key.
+///
+/// Prefixes are shortened to the fewest bytes that still tell the signers in the map apart.
+///
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SignatureMap {
+    entries: Vec<(Signer, Vec<u8>)>,
+}
+
+impl SignatureMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of signatures in this map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if this map has no signatures.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds the signature `public_key` made over a message,
+    /// replacing any earlier signature by the same key.
+    ///
+    /// A decoded signature whose prefix `public_key` starts with counts as being by the
+    /// same key, as the two prefixes could not be told apart once encoded.
+    ///
+    pub fn insert(&mut self, public_key: PublicKey, signature: impl AsRef<[u8]>) {
+        let signature = signature.as_ref().to_vec();
+
+        // every entry before the first match is kept, so the replacement takes its place
+        let index = self
+            .entries
+            .iter()
+            .position(|(signer, _)| signer.matches(&public_key))
+            .unwrap_or(self.entries.len());
+
+        self.entries.retain(|(signer, _)| !signer.matches(&public_key));
+        self.entries.insert(index, (Signer::PublicKey(public_key), signature));
+    }
+
+    /// Signs `message` with `private_key` and adds the signature.
+    pub fn sign(&mut self, private_key: &PrivateKey, message: &[u8]) {
+        self.insert(private_key.public_key(), private_key.sign(message));
+    }
+
+    /// Adds the signatures of `other` that are not already in this map.
+    ///
+    /// As with [`SignatureMap::insert`], a signature by a full public key replaces
+    /// a decoded signature whose prefix the key starts with.
+    ///
+    pub fn merge(&mut self, other: SignatureMap) {
+        for (signer, signature) in other.entries {
+            match &signer {
+                Signer::PublicKey(key) if !self.contains_signer(key) => {
+                    self.insert(key.clone(), signature);
+                }
+
+                Signer::PublicKey(_) => {}
+
+                // a prefix is dropped if the full key it was shortened from is already here
+                Signer::Prefix(..) => {
+                    let known =
+                        self.entries.iter().any(|(existing, existing_signature)| match existing {
+                            Signer::PublicKey(key) => signer.matches(key),
+                            Signer::Prefix(..) => {
+                                *existing == signer && *existing_signature == signature
+                            }
+                        });
+
+                    if !known {
+                        self.entries.push((signer, signature));
+                    }
+                }
+            }
+        }
+    }
+
+    fn contains_signer(&self, public_key: &PublicKey) -> bool {
+        self.entries
+            .iter()
+            .any(|(signer, _)| matches!(signer, Signer::PublicKey(key) if key == public_key))
+    }
+
+    /// Returns the signature pairs of this map, with public key prefixes
+    /// shortened to the fewest bytes that tell the signers apart.
+    pub fn pairs(&self) -> Vec<SignaturePair> {
+        let key_bytes: Vec<_> = self.entries.iter().map(|(signer, _)| signer.key_bytes()).collect();
+
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(i, (signer, signature))| {
+                let (pub_key_prefix, algorithm) = match signer {
+                    Signer::PublicKey(key) => {
+                        let bytes = &key_bytes[i];
+
+                        let shared = key_bytes
+                            .iter()
+                            .enumerate()
+                            .filter(|(j, _)| *j != i)
+                            .map(|(_, other)| common_prefix_len(bytes, other))
+                            .max()
+                            .unwrap_or(0);
+
+                        (bytes[..(shared + 1).min(bytes.len())].to_vec(), key.algorithm())
+                    }
+
+                    Signer::Prefix(prefix, algorithm) => (prefix.clone(), *algorithm),
+                };
+
+                SignaturePair { pub_key_prefix, signature: signature.clone(), algorithm }
+            })
+            .collect()
+    }
+
+    /// Returns the public keys out of `candidates` that have a valid signature
+    /// over `message` in this map.
+    pub fn verify(&self, message: &[u8], candidates: &[PublicKey]) -> Vec<PublicKey> {
+        let mut verified: Vec<PublicKey> = Vec::new();
+
+        for pair in self.pairs() {
+            for candidate in candidates {
+                if !verified.contains(candidate)
+                    && pair.matches(candidate)
+                    && candidate.verify_raw(message, pair.signature())
+                {
+                    verified.push(candidate.clone());
+                }
+            }
+        }
+
+        verified
+    }
+
+    /// Returns the HAPI `SignatureMap` protobuf encoding of this map.
+    pub fn to_protobuf_bytes(&self) -> Vec<u8> {
+        proto::SignatureMap {
+            sig_pair: self.pairs().iter().map(SignaturePair::to_protobuf).collect(),
+        }
+        .encode_to_vec()
+    }
+
+    /// Decodes a map from its HAPI `SignatureMap` protobuf encoding.
+    ///
+    /// Only the public key prefixes of the signers are known after decoding; use
+    /// [`SignatureMap::verify`] to match the signatures against full public keys.
+    ///
+    pub fn from_protobuf_bytes(data: &[u8]) -> Result<Self, KeyError> {
+        let map = proto::SignatureMap::decode(data)?;
+
+        let entries = map
+            .sig_pair
+            .into_iter()
+            .map(|pair| {
+                let pair = SignaturePair::from_protobuf(pair)?;
+
+                Ok((Signer::Prefix(pair.pub_key_prefix, pair.algorithm), pair.signature))
+            })
+            .collect::<Result<_, KeyError>>()?;
+
+        Ok(Self { entries })
+    }
+}
+
+fn common_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).take_while(|(a, b)| a == b).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SignatureMap;
+    use crate::{KeyAlgorithm, KeyError, PrivateKey, PublicKey};
+
+    const MESSAGE: &[u8] = b"hello, world";
+
+    #[test]
+    fn test_sign() {
+        let private_key = PrivateKey::generate();
+
+        let mut map = SignatureMap::new();
+        map.sign(&private_key, MESSAGE);
+        map.sign(&private_key, MESSAGE);
+
+        let pairs = map.pairs();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].pub_key_prefix().len(), 1);
+        assert_eq!(pairs[0].signature(), private_key.sign(MESSAGE).as_ref());
+        assert_eq!(pairs[0].algorithm(), KeyAlgorithm::Ed25519);
+    }
+
+    /// Returns an Ed25519 public key whose raw bytes start with `prefix`.
+    fn public_key_with_prefix(prefix: &[u8]) -> PublicKey {
+        let mut bytes = [0u8; 32];
+        bytes[..prefix.len()].copy_from_slice(prefix);
+
+        // about half of all encodings are valid points
+        (0..=255)
+            .find_map(|last| {
+                bytes[31] = last;
+                PublicKey::from_bytes_ed25519(&bytes).ok()
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_shortest_unique_prefixes() {
+        let mut map = SignatureMap::new();
+        for prefix in &[&[1, 1, 1, 3][..], &[1, 1, 1, 4], &[2]] {
+            map.insert(public_key_with_prefix(prefix), [0u8; 64]);
+        }
+
+        let prefixes: Vec<_> =
+            map.pairs().iter().map(|pair| pair.pub_key_prefix().to_vec()).collect();
+        assert_eq!(prefixes, vec![vec![1, 1, 1, 3], vec![1, 1, 1, 4], vec![2]]);
+    }
+
+    #[test]
+    fn test_merge_and_verify() -> Result<(), KeyError> {
+        let ed25519_key = PrivateKey::generate();
+        let ecdsa_key = PrivateKey::generate_ecdsa();
+        let other_key = PrivateKey::generate();
+
+        let mut map = SignatureMap::new();
+        map.sign(&ed25519_key, MESSAGE);
+
+        let mut other_map = SignatureMap::new();
+        other_map.sign(&ecdsa_key, MESSAGE);
+        other_map.sign(&ed25519_key, MESSAGE);
+
+        map.merge(other_map);
+        assert_eq!(map.len(), 2);
+
+        let candidates = [ed25519_key.public_key(), ecdsa_key.public_key(), other_key.public_key()];
+        let expected = vec![ed25519_key.public_key(), ecdsa_key.public_key()];
+
+        assert_eq!(map.verify(MESSAGE, &candidates), expected);
+        assert_eq!(map.verify(b"goodbye, world", &candidates), vec![]);
+
+        // only the prefixes survive encoding, but that is enough to verify against candidates
+        let decoded = SignatureMap::from_protobuf_bytes(&map.to_protobuf_bytes())?;
+        assert_eq!(decoded.pairs(), map.pairs());
+        assert_eq!(decoded.verify(MESSAGE, &candidates), expected);
+
+        // merging the decoded map back does not duplicate signatures
+        let mut merged = decoded.clone();
+        merged.merge(decoded);
+        assert_eq!(merged.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_decoded() -> Result<(), KeyError> {
+        let ed25519_key = PrivateKey::generate();
+        let ecdsa_key = PrivateKey::generate_ecdsa();
+        let candidates = [ed25519_key.public_key(), ecdsa_key.public_key()];
+
+        let mut map = SignatureMap::new();
+        map.sign(&ed25519_key, MESSAGE);
+        map.sign(&ecdsa_key, MESSAGE);
+
+        // signing again with a key the decoded map already has replaces its signature
+        let mut decoded = SignatureMap::from_protobuf_bytes(&map.to_protobuf_bytes())?;
+        decoded.sign(&ed25519_key, MESSAGE);
+        assert_eq!(decoded.len(), 2);
+
+        let pairs = SignatureMap::from_protobuf_bytes(&decoded.to_protobuf_bytes())?.pairs();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs.iter().filter(|pair| pair.matches(&candidates[0])).count(), 1);
+        assert_eq!(pairs.iter().filter(|pair| pair.matches(&candidates[1])).count(), 1);
+        assert_eq!(decoded.verify(MESSAGE, &candidates), candidates);
+
+        // as does merging in a map that has the full key, in either direction
+        let mut decoded = SignatureMap::from_protobuf_bytes(&map.to_protobuf_bytes())?;
+        decoded.merge(map.clone());
+        assert_eq!(decoded.pairs(), map.pairs());
+
+        let mut merged = map.clone();
+        merged.merge(SignatureMap::from_protobuf_bytes(&map.to_protobuf_bytes())?);
+        assert_eq!(merged, map);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_protobuf() {
+        // a pair with a contract signature
+        assert!(matches!(
+            SignatureMap::from_protobuf_bytes(&[0x0a, 0x02, 0x12, 0x00]),
+            Err(KeyError::UnsupportedKeyType(_))
+        ));
+    }
+}
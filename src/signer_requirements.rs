@@ -0,0 +1,294 @@
+use std::collections::HashSet;
+
+use crate::key::Key;
+use crate::public_key::PublicKey;
+
+/// Which signatures a key still needs, given the public keys that can already sign.
+///
+/// Returned by [`Key::required_signers`].
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerRequirements {
+    satisfied: bool,
+    missing: Vec<PublicKey>,
+    additional: Option<Vec<PublicKey>>,
+}
+
+impl SignerRequirements {
+    /// Returns `true` if the available signers can satisfy the key on their own.
+    pub fn is_satisfied(&self) -> bool {
+        self.satisfied
+    }
+
+    /// Returns `true` if the key can be satisfied by signatures at all.
+    ///
+    /// Keys that need a contract to act, or that contain an empty key list or a threshold
+    /// of zero or above the number of keys where that list is required, can never be
+    /// satisfied by signatures.
+    ///
+    pub fn is_satisfiable(&self) -> bool {
+        self.additional.is_some()
+    }
+
+    /// Returns every public key in the key that is not among the available signers,
+    /// in the order they appear in the key.
+    pub fn missing(&self) -> &[PublicKey] {
+        &self.missing
+    }
+
+    /// Returns a smallest set of additional signers that, together with the available
+    /// signers, satisfies the key; empty if the key is already satisfied.
+    ///
+    /// Returns `None` if the key cannot be satisfied by signatures.
+    ///
+    /// The set is found by exhaustive search, and so is minimal, unless the key has too many
+    /// missing signers to search; then a small but not necessarily minimal set is returned.
+    ///
+    pub fn additional(&self) -> Option<&[PublicKey]> {
+        self.additional.as_deref()
+    }
+}
+
+impl Key {
+    /// Works out which signatures this key still needs when the `available`
+    /// public keys are able to sign.
+    pub fn required_signers(&self, available: &[PublicKey]) -> SignerRequirements {
+        let available: HashSet<&PublicKey> = available.iter().collect();
+
+        let mut missing = Vec::new();
+        collect_missing(self, &available, &mut missing);
+
+        let additional = minimal_signers(self, &available, &missing);
+        let satisfied = matches!(additional.as_deref(), Some([]));
+
+        SignerRequirements { satisfied, missing, additional }
+    }
+}
+
+fn collect_missing(key: &Key, available: &HashSet<&PublicKey>, missing: &mut Vec<PublicKey>) {
+    match key {
+        Key::PublicKey(key) if !available.contains(key) && !missing.contains(key) => {
+            missing.push(key.clone());
+        }
+
+        Key::KeyList(list) => {
+            for key in list.iter() {
+                collect_missing(key, available, missing);
+            }
+        }
+
+        _ => {}
+    }
+}
+
+/// The most sets of signers to try before settling for the greedy estimate.
+const SEARCH_LIMIT: usize = 10_000;
+
+fn minimal_signers(
+    key: &Key,
+    available: &HashSet<&PublicKey>,
+    missing: &[PublicKey],
+) -> Option<Vec<PublicKey>> {
+    let estimate = greedy_signers(key, available)?;
+    let mut budget = SEARCH_LIMIT;
+
+    // look for a smaller set than the estimate, smallest first
+    for size in 0..estimate.len() {
+        let mut indices: Vec<usize> = (0..size).collect();
+
+        loop {
+            if budget == 0 {
+                return Some(estimate);
+            }
+
+            budget -= 1;
+
+            let mut signers = available.clone();
+            signers.extend(indices.iter().map(|&i| &missing[i]));
+
+            if is_satisfied_with(key, &signers) {
+                return Some(indices.iter().map(|&i| missing[i].clone()).collect());
+            }
+
+            if !next_combination(&mut indices, missing.len()) {
+                break;
+            }
+        }
+    }
+
+    Some(estimate)
+}
+
+/// Advances `indices` to the next combination of its size out of `0..len`,
+/// returning `false` once every combination has been visited.
+fn next_combination(indices: &mut [usize], len: usize) -> bool {
+    let size = indices.len();
+
+    for i in (0..size).rev() {
+        if indices[i] < len - size + i {
+            indices[i] += 1;
+
+            for j in i + 1..size {
+                indices[j] = indices[j - 1] + 1;
+            }
+
+            return true;
+        }
+    }
+
+    false
+}
+
+fn is_satisfied_with(key: &Key, signers: &HashSet<&PublicKey>) -> bool {
+    match key {
+        Key::PublicKey(key) => signers.contains(key),
+
+        Key::KeyList(list) => {
+            let required = list.threshold.unwrap_or_else(|| list.len());
+
+            (1..=list.len()).contains(&required)
+                && list.iter().filter(|key| is_satisfied_with(key, signers)).count() >= required
+        }
+
+        Key::ContractId(_) | Key::DelegatableContractId(_) => false,
+    }
+}
+
+/// Picks the cheapest branches of every key list, which gives a small set of signers
+/// quickly but may miss a smaller one when keys are shared between branches.
+fn greedy_signers(key: &Key, available: &HashSet<&PublicKey>) -> Option<Vec<PublicKey>> {
+    match key {
+        Key::PublicKey(key) if available.contains(key) => Some(Vec::new()),
+
+        Key::PublicKey(key) => Some(vec![key.clone()]),
+
+        Key::KeyList(list) => {
+            let required = list.threshold.unwrap_or_else(|| list.len());
+
+            // also covers the empty list, like `is_satisfied_with`
+            if !(1..=list.len()).contains(&required) {
+                return None;
+            }
+            let mut options: Vec<_> =
+                list.iter().filter_map(|key| greedy_signers(key, available)).collect();
+
+            if options.len() < required {
+                return None;
+            }
+
+            // take the cheapest branches one at a time, counting only the signers
+            // not already needed by an earlier branch
+            let mut signers: Vec<PublicKey> = Vec::new();
+
+            for _ in 0..required {
+                let (index, _) = options.iter().enumerate().min_by_key(|(_, option)| {
+                    option.iter().filter(|key| !signers.contains(key)).count()
+                })?;
+
+                for key in options.remove(index) {
+                    if !signers.contains(&key) {
+                        signers.push(key);
+                    }
+                }
+            }
+
+            Some(signers)
+        }
+
+        Key::ContractId(_) | Key::DelegatableContractId(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ContractId, Key, KeyList, PrivateKey, PublicKey};
+
+    fn public_keys(count: usize) -> Vec<PublicKey> {
+        (0..count).map(|_| PrivateKey::generate().public_key()).collect()
+    }
+
+    fn threshold_list(threshold: usize, keys: Vec<Key>) -> Key {
+        let mut list: KeyList = keys.into_iter().collect();
+        list.threshold = Some(threshold);
+        list.into()
+    }
+
+    #[test]
+    fn test_nested_thresholds() {
+        let keys = public_keys(4);
+
+        // 2 of [a, b, 1 of [c, d]]
+        let inner = threshold_list(1, vec![keys[2].clone().into(), keys[3].clone().into()]);
+        let key = threshold_list(2, vec![keys[0].clone().into(), keys[1].clone().into(), inner]);
+
+        let requirements = key.required_signers(&[keys[2].clone()]);
+        assert!(!requirements.is_satisfied());
+        assert!(requirements.is_satisfiable());
+        assert_eq!(requirements.missing(), &[keys[0].clone(), keys[1].clone(), keys[3].clone()]);
+        assert_eq!(requirements.additional(), Some(&[keys[0].clone()][..]));
+
+        let requirements = key.required_signers(&[]);
+        assert_eq!(requirements.additional().map(<[_]>::len), Some(2));
+
+        let requirements = key.required_signers(&[keys[1].clone(), keys[3].clone()]);
+        assert!(requirements.is_satisfied());
+        assert_eq!(requirements.additional(), Some(&[][..]));
+    }
+
+    #[test]
+    fn test_shared_keys() {
+        let keys = public_keys(3);
+
+        // [1 of [a, b], 1 of [c, a]] is satisfied by `a` alone
+        let first = threshold_list(1, vec![keys[0].clone().into(), keys[1].clone().into()]);
+        let second = threshold_list(1, vec![keys[2].clone().into(), keys[0].clone().into()]);
+        let key = Key::from(vec![first, second].into_iter().collect::<KeyList>());
+
+        let requirements = key.required_signers(&[]);
+        assert_eq!(requirements.missing().len(), 3);
+        assert_eq!(requirements.additional(), Some(&[keys[0].clone()][..]));
+    }
+
+    #[test]
+    fn test_next_combination() {
+        let mut indices = vec![0, 1];
+        let mut combinations = vec![indices.clone()];
+
+        while super::next_combination(&mut indices, 4) {
+            combinations.push(indices.clone());
+        }
+
+        assert_eq!(combinations, [[0, 1], [0, 2], [0, 3], [1, 2], [1, 3], [2, 3]]);
+        assert!(!super::next_combination(&mut [], 4));
+    }
+
+    #[test]
+    fn test_unsatisfiable() {
+        let keys = public_keys(1);
+        let contract = Key::ContractId(ContractId::new(0, 0, 1234));
+
+        let key = Key::from(
+            vec![keys[0].clone().into(), contract.clone()].into_iter().collect::<KeyList>(),
+        );
+
+        let requirements = key.required_signers(&keys);
+        assert!(!requirements.is_satisfied());
+        assert!(!requirements.is_satisfiable());
+        assert_eq!(requirements.additional(), None);
+
+        // ... unless the contract is not required
+        let key = threshold_list(1, vec![keys[0].clone().into(), contract]);
+        assert!(key.required_signers(&keys).is_satisfied());
+
+        assert!(!Key::from(KeyList::new()).required_signers(&keys).is_satisfiable());
+
+        // a threshold of zero or above the number of keys is never met, even with every signer
+        for &threshold in &[0, 2] {
+            let key = threshold_list(threshold, vec![keys[0].clone().into()]);
+            let requirements = key.required_signers(&keys);
+
+            assert!(!requirements.is_satisfied(), "{}", threshold);
+            assert!(!requirements.is_satisfiable(), "{}", threshold);
+        }
+    }
+}
@@ -3,12 +3,14 @@ use std::cell::RefCell;
 use byteorder::{BigEndian, ByteOrder};
 use hmac::{Hmac, Mac, NewMac};
 use sha2::Sha512;
+use zeroize::Zeroize;
 
-pub fn derive(key_data: &mut [u8], chain_code: &mut [u8], index: u32) {
-    thread_local! {
-        static BUF: RefCell<[u8; 37]> = RefCell::new([0; 37]);
-    }
+thread_local! {
+    // holds the parent key while deriving, and is wiped afterwards
+    static BUF: RefCell<[u8; 37]> = RefCell::new([0; 37]);
+}
 
+pub fn derive(key_data: &mut [u8], chain_code: &mut [u8], index: u32) {
     BUF.with(|buf| {
         let mut buf = buf.borrow_mut();
 
@@ -21,10 +23,28 @@ pub fn derive(key_data: &mut [u8], chain_code: &mut [u8], index: u32) {
 
         let mut mac = Hmac::<Sha512>::new_from_slice(chain_code).unwrap();
         mac.update(&*buf);
+        buf.zeroize();
 
-        let digest = mac.finalize().into_bytes();
+        let mut digest = mac.finalize().into_bytes();
 
         key_data.copy_from_slice(&digest[0..32]);
         chain_code.copy_from_slice(&digest[32..]);
+        digest.as_mut_slice().zeroize();
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{derive, BUF};
+
+    #[test]
+    fn test_derive_wipes_buffer() {
+        let mut key_data = [7; 32];
+        let mut chain_code = [9; 32];
+
+        derive(&mut key_data, &mut chain_code, 0);
+
+        assert_ne!(key_data, [7; 32]);
+        BUF.with(|buf| assert_eq!(*buf.borrow(), [0; 37]));
+    }
+}
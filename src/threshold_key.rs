@@ -0,0 +1,125 @@
+use std::convert::TryFrom;
+
+use crate::key::Key;
+use crate::key_list::KeyList;
+use crate::key_list_error::KeyListError;
+
+/// A list of keys of which at least `threshold` must sign.
+///
+/// Unlike a [`KeyList`], a `ThresholdKey` can only be constructed if it would be accepted
+/// by the Hedera™ Network: every list in it is non-empty, has a threshold of at least one and
+/// at most the number of its keys, and the whole key is no deeper than [`KeyList::MAX_DEPTH`].
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThresholdKey(KeyList);
+
+impl ThresholdKey {
+    /// Returns a threshold key requiring `threshold` of `keys` to sign.
+    pub fn new<I, K>(threshold: usize, keys: I) -> Result<Self, KeyListError>
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<Key>,
+    {
+        let mut list: KeyList = keys.into_iter().collect();
+        list.threshold = Some(threshold);
+
+        Self::try_from(list)
+    }
+
+    /// Returns the number of keys that must sign.
+    pub fn threshold(&self) -> usize {
+        // UNWRAP: the threshold is checked on construction
+        self.0.threshold.unwrap()
+    }
+
+    /// Returns the keys of this threshold key.
+    pub fn keys(&self) -> &[Key] {
+        &self.0.keys
+    }
+}
+
+impl TryFrom<KeyList> for ThresholdKey {
+    type Error = KeyListError;
+
+    fn try_from(list: KeyList) -> Result<Self, KeyListError> {
+        if list.threshold.is_none() {
+            return Err(KeyListError::MissingThreshold);
+        }
+
+        list.validate()?;
+
+        Ok(Self(list))
+    }
+}
+
+impl From<ThresholdKey> for KeyList {
+    fn from(key: ThresholdKey) -> Self {
+        key.0
+    }
+}
+
+impl From<ThresholdKey> for Key {
+    fn from(key: ThresholdKey) -> Self {
+        Key::KeyList(key.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use super::ThresholdKey;
+    use crate::{Key, KeyError, KeyList, KeyListError, PublicKey};
+
+    const PUBLIC_KEY: &str =
+        "302a300506032b6570032100d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+    fn public_keys(count: usize) -> Result<Vec<PublicKey>, KeyError> {
+        (0..count).map(|_| PublicKey::from_str(PUBLIC_KEY)).collect()
+    }
+
+    #[test]
+    fn test_new() -> Result<(), KeyError> {
+        let key = ThresholdKey::new(2, public_keys(3)?)?;
+
+        assert_eq!(key.threshold(), 2);
+        assert_eq!(key.keys().len(), 3);
+
+        let list = KeyList::from(key);
+        assert_eq!(list.threshold, Some(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_threshold() -> Result<(), KeyError> {
+        assert_eq!(
+            ThresholdKey::new(0, public_keys(3)?),
+            Err(KeyListError::ThresholdOutOfRange { threshold: 0, len: 3 })
+        );
+        assert_eq!(
+            ThresholdKey::new(4, public_keys(3)?),
+            Err(KeyListError::ThresholdOutOfRange { threshold: 4, len: 3 })
+        );
+        assert_eq!(ThresholdKey::new(1, Vec::<Key>::new()), Err(KeyListError::Empty));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_key_list() -> Result<(), KeyError> {
+        let list: KeyList = public_keys(2)?.into_iter().collect();
+
+        assert_eq!(ThresholdKey::try_from(list.clone()), Err(KeyListError::MissingThreshold));
+
+        // an invalid list nested in an otherwise valid one
+        let mut outer: KeyList =
+            vec![Key::from(list), Key::from(KeyList::new())].into_iter().collect();
+        outer.threshold = Some(1);
+
+        assert_eq!(ThresholdKey::try_from(outer), Err(KeyListError::Empty));
+
+        Ok(())
+    }
+}
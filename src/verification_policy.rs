@@ -0,0 +1,104 @@
+use std::convert::TryFrom;
+
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::IsIdentity;
+use ed25519_dalek::Verifier;
+use sha2::{Digest, Sha512};
+
+use crate::verify_error::VerifyError;
+
+/// Which Ed25519 signatures [`PublicKey::verify_with_policy`](crate::PublicKey::verify_with_policy)
+/// accepts.
+///
+/// The policies only differ on edge cases that an honest signer never produces, but which
+/// a malicious one can use to have different verifiers disagree about a signature.
+/// ECDSA(secp256k1) signatures are verified the same way under every policy, and must
+/// have a low `s`.
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VerificationPolicy {
+    /// RFC 8032 without the cofactor: `R` must be canonically encoded and not of small
+    /// order, and `[s]B = R + [k]A` must hold exactly.
+    Strict,
+
+    /// The cofactored rules of ZIP-215: any encoding of `R` that decodes is accepted, and
+    /// `[8][s]B = [8]R + [8][k]A` must hold.
+    Zip215,
+
+    /// What [`PublicKey::verify`](crate::PublicKey::verify) has always accepted: `ed25519-dalek`'s
+    /// `verify`, which allows an `R` of small order.
+    Legacy,
+}
+
+impl VerificationPolicy {
+    pub(crate) fn verify_ed25519(
+        self,
+        key: &ed25519_dalek::PublicKey,
+        message: &[u8],
+        signature: &[u8; 64],
+    ) -> Result<(), VerifyError> {
+        let r_bytes = CompressedEdwardsY::from_slice(&signature[..32]);
+
+        let mut s = [0; 32];
+        s.copy_from_slice(&signature[32..]);
+        let s = Scalar::from_canonical_bytes(s).ok_or(VerifyError::InvalidScalar)?;
+
+        match self {
+            VerificationPolicy::Strict => {
+                let r = r_bytes.decompress().ok_or(VerifyError::InvalidPoint)?;
+
+                if r.compress() != r_bytes {
+                    return Err(VerifyError::NonCanonicalPoint);
+                }
+
+                if r.is_small_order() {
+                    return Err(VerifyError::SmallOrderPoint);
+                }
+
+                if !(commitment(key, message, &r_bytes, &s) - r).is_identity() {
+                    return Err(VerifyError::Mismatch);
+                }
+            }
+
+            VerificationPolicy::Zip215 => {
+                let r = r_bytes.decompress().ok_or(VerifyError::InvalidPoint)?;
+
+                if !(commitment(key, message, &r_bytes, &s) - r).mul_by_cofactor().is_identity() {
+                    return Err(VerifyError::Mismatch);
+                }
+            }
+
+            VerificationPolicy::Legacy => {
+                // UNWRAP: the signature is 64 bytes
+                let signature = ed25519_dalek::Signature::try_from(&signature[..]).unwrap();
+
+                key.verify(message, &signature).map_err(|_| VerifyError::Mismatch)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `[s]B - [k]A`, which equals `R` for a valid signature.
+fn commitment(
+    key: &ed25519_dalek::PublicKey,
+    message: &[u8],
+    r_bytes: &CompressedEdwardsY,
+    s: &Scalar,
+) -> EdwardsPoint {
+    // UNWRAP: public keys are checked to be valid points on import
+    let a = CompressedEdwardsY::from_slice(key.as_bytes()).decompress().unwrap();
+
+    EdwardsPoint::vartime_double_scalar_mul_basepoint(&challenge(key, message, r_bytes), &-a, s)
+}
+
+/// Returns the challenge `k = SHA-512(R || A || M)` of an Ed25519 signature.
+pub(crate) fn challenge(
+    key: &ed25519_dalek::PublicKey,
+    message: &[u8],
+    r_bytes: &CompressedEdwardsY,
+) -> Scalar {
+    Scalar::from_hash(Sha512::new().chain(r_bytes.as_bytes()).chain(key.as_bytes()).chain(message))
+}
@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+use crate::key_algorithm::KeyAlgorithm;
+
+/// Why a signature failed to verify: it was malformed, made by a key of another
+/// algorithm, or does not match.
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum VerifyError {
+    #[error("expected a signature by an {expected} key but found one by an {actual} key")]
+    AlgorithmMismatch { expected: KeyAlgorithm, actual: KeyAlgorithm },
+
+    #[error("a scalar of the signature is out of range")]
+    InvalidScalar,
+
+    #[error("the signature commitment (R) is not a point on the curve")]
+    InvalidPoint,
+
+    #[error("the signature commitment (R) is not canonically encoded")]
+    NonCanonicalPoint,
+
+    #[error("the signature commitment (R) is a point of small order")]
+    SmallOrderPoint,
+
+    #[error("the signature does not match the message and public key")]
+    Mismatch,
+}